@@ -0,0 +1,381 @@
+//! Accessibility checks of resolved themes.
+//! Computes the WCAG 2.x contrast ratio of the text colors of the widgets
+//! against their backgrounds, reports the pairs below the AA or AAA thresholds
+//! and corrects them.
+
+
+
+use crate::{ Background, Color, Theme, };
+
+use std::{ collections::HashMap, fmt, sync::Arc, };
+
+
+
+/// Number of lightness steps tried in each direction when correcting a color.
+const STEPS: usize = 200;
+
+
+
+/// WCAG conformance level of a contrast ratio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Minimum contrast of 4.5:1 for normal text.
+    AA,
+
+    /// Enhanced contrast of 7:1 for normal text.
+    AAA,
+}
+
+impl Level {
+    /// Minimum contrast ratio of normal text at this level.
+    pub const fn ratio(self) -> f32 {
+        match self {
+            Level::AA => 4.5,
+            Level::AAA => 7.0,
+        }
+    }
+}
+
+
+
+/// Text and background pair with a contrast below a conformance level.
+#[derive(Clone, Debug)]
+pub struct Violation {
+    /// Section of the theme of the widget (e.g. `button`).
+    pub section: &'static str,
+
+    /// Key of the widget theme.
+    pub entry: String,
+
+    /// Path to the text color inside the widget theme (e.g. `hovered.text`).
+    pub field: String,
+
+    /// Text color, blended over the background.
+    pub foreground: Color,
+
+    /// Background color, blended over the backdrop.
+    /// For gradients, this is the stop with the lowest contrast.
+    pub background: Color,
+
+    /// Contrast ratio of the pair.
+    pub ratio: f32,
+
+    /// Lowest level the pair does not meet. A violation of `AA` also fails `AAA`.
+    pub level: Level,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} `{}`.{}: contrast {:.2}:1 is below {:?} ({}:1)", self.section, self.entry, self.field, self.ratio, self.level, self.level.ratio())
+    }
+}
+
+
+
+/// Contrast audit of a resolved theme.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// Pairs below the AAA threshold, sorted by section, entry and field.
+    pub violations: Vec<Violation>,
+}
+
+impl Report {
+    /// Checks the text colors of the buttons, text inputs, pick lists, tooltips
+    /// and applications of the theme against their backgrounds.
+    /// Translucent backgrounds are blended over `backdrop` (usually the window
+    /// background), whose alpha is ignored.
+    pub fn new(theme: &Theme, backdrop: &Color) -> Self {
+        let mut violations = Vec::new();
+
+        let mut check = |section: &'static str, entry: &str, field: String, text: &Color, background: Vec<Color>| {
+            let worst = background.iter()
+                .map(|background| {
+                    let background = blend(background, backdrop);
+                    let foreground = blend(text, &background);
+
+                    (foreground.contrast(&background), foreground, background)
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+
+            let (ratio, foreground, background) = match worst {
+                Some(worst) => worst,
+                _ => return,
+            };
+
+            let level = match ratio {
+                r if r < Level::AA.ratio() => Level::AA,
+                r if r < Level::AAA.ratio() => Level::AAA,
+                _ => return,
+            };
+
+            violations.push( Violation { section, entry: entry.into(), field, foreground, background, ratio, level } );
+        };
+
+        for (name, application) in &theme.application {
+            check("application", name, "text".into(), &application.text, vec![*application.background]);
+        }
+
+        for (name, button) in &theme.button {
+            for (state, label) in button.state.iter().zip(crate::Button::STATES.iter()) {
+                check("button", name, format!("{}.text", label), &state.text, colors(&state.background));
+            }
+        }
+
+        for (name, textinput) in &theme.textinput {
+            for (state, label) in textinput.state.iter().zip(crate::TextInput::STATES.iter()) {
+                let background = vec![*state.background];
+
+                // The disabled state shows the disabled color instead of the value.
+                match *label {
+                    "disabled" => check("textinput", name, "disabled.disabledc".into(), &textinput.colors[3], background.clone()),
+                    _ => check("textinput", name, format!("{}.value", label), &textinput.colors[1], background.clone()),
+                }
+
+                check("textinput", name, format!("{}.placeholder", label), &textinput.colors[0], background);
+            }
+        }
+
+        for (name, picklist) in &theme.picklist {
+            for (state, label) in picklist.state.iter().zip(crate::Picklist::STATES.iter()) {
+                check("picklist", name, format!("{}.text", label), &state.text, colors(&state.background));
+                check("picklist", name, format!("{}.placeholder", label), &state.placeholder, colors(&state.background));
+            }
+
+            check("picklist", name, "menu.text".into(), &picklist.menu.text[0], colors(&picklist.menu.background[0]));
+            check("picklist", name, "menu.stext".into(), &picklist.menu.text[1], colors(&picklist.menu.background[1]));
+        }
+
+        for (name, tooltip) in &theme.tooltip {
+            check("tooltip", name, "text".into(), &tooltip.text, colors(&tooltip.background));
+        }
+
+        violations.sort_by(|a, b| (a.section, &a.entry, &a.field).cmp(&(b.section, &b.entry, &b.field)));
+
+        Report { violations }
+    }
+
+    /// Returns the violations that fail the given level.
+    pub fn failing(&self, level: Level) -> impl Iterator<Item = &Violation> {
+        self.violations.iter().filter(move |v| v.level <= level)
+    }
+
+    /// Checks if every pair of the theme meets the given level.
+    pub fn passes(&self, level: Level) -> bool {
+        self.failing(level).next().is_none()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for violation in &self.violations {
+            writeln!(f, "{}", violation)?;
+        }
+
+        Ok(())
+    }
+}
+
+
+
+impl Color {
+    /// Returns the WCAG relative luminance of the color, ignoring its alpha.
+    pub fn luminance(&self) -> f32 {
+        let color = iced::Color::from(*self);
+
+        let linear = |c: f32| match c <= 0.04045 {
+            true => c / 12.92,
+            false => ((c + 0.055) / 1.055).powf(2.4),
+        };
+
+        0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+    }
+
+    /// Returns the WCAG contrast ratio between this color and `other`, in `[1, 21]`.
+    pub fn contrast(&self, other: &Color) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+}
+
+
+
+impl Theme {
+    /// Returns a copy of the theme in which the text colors of the buttons, pick lists,
+    /// text inputs and tooltips have at least the `target` contrast ratio against their backgrounds.
+    /// The OKLCH lightness of the offending colors is changed by the smallest amount that
+    /// reaches the target, or that gets closest to it. Translucent backgrounds are blended
+    /// over the `backdrop`, as in `Report::new`, so the fixed theme passes its report.
+    /// The corrected colors are not added to the color table, and are exported inline by `to_serial`.
+    /// The styles that already reach the target are kept, so they are exported as they were parsed.
+    pub fn fix_contrast(&self, target: f32, backdrop: &Color) -> Theme {
+        let mut theme = self.clone();
+
+        // Returns the corrected text color, or `None` if it already has enough contrast.
+        let fix = |text: &Arc<Color>, backgrounds: &[&Background]| {
+            let backgrounds: Vec<Color> = backgrounds.iter()
+                .flat_map(|background| colors(background))
+                .map(|background| blend(&background, backdrop))
+                .collect();
+
+            adjust(text, &backgrounds, target).map(Arc::new)
+        };
+
+        // Only the states and entries with a corrected color are replaced, and the
+        // states shared by several entries are replaced once, so they stay shared.
+        let mut states = HashMap::new();
+
+        for button in theme.button.values_mut() {
+            let state = button.state.clone().map(|state| shared(&mut states, &state, |state| {
+                let text = fix( &state.text, &[&state.background] )?;
+                Some( crate::button::State { text, ..state.clone() } )
+            }));
+
+            if changed(&state, &button.state) {
+                *button = Arc::new( crate::Button { state } );
+            }
+        }
+
+        let (mut states, mut menus) = (HashMap::new(), HashMap::new());
+
+        for picklist in theme.picklist.values_mut() {
+            let state = picklist.state.clone().map(|state| shared(&mut states, &state, |state| {
+                let text = fix( &state.text, &[&state.background] );
+                let placeholder = fix( &state.placeholder, &[&state.background] );
+
+                match (text, placeholder) {
+                    (None, None) => None,
+                    (text, placeholder) => Some( crate::picklist::State {
+                        text: text.unwrap_or_else(|| state.text.clone()),
+                        placeholder: placeholder.unwrap_or_else(|| state.placeholder.clone()),
+                        ..state.clone()
+                    } ),
+                }
+            }));
+
+            let menu = shared(&mut menus, &picklist.menu, |menu| {
+                let text = [ fix( &menu.text[0], &[&menu.background[0]] ), fix( &menu.text[1], &[&menu.background[1]] ) ];
+
+                match text {
+                    [None, None] => None,
+                    [first, second] => Some( crate::picklist::Menu {
+                        text: [ first.unwrap_or_else(|| menu.text[0].clone()), second.unwrap_or_else(|| menu.text[1].clone()) ],
+                        ..menu.clone()
+                    } ),
+                }
+            });
+
+            if changed(&state, &picklist.state) || !Arc::ptr_eq(&menu, &picklist.menu) {
+                *picklist = Arc::new( crate::Picklist { state, menu } );
+            }
+        }
+
+        for textinput in theme.textinput.values_mut() {
+            // The colors are shared by the states, and must contrast with all of their backgrounds.
+            let backgrounds: Vec<Background> = textinput.state.iter().map(|state| Background::Color( state.background.clone() )).collect();
+            let backgrounds: Vec<&Background> = backgrounds.iter().collect();
+
+            let fixed = [
+                (0, fix( &textinput.colors[0], &backgrounds )),
+                (1, fix( &textinput.colors[1], &backgrounds[..3] )),
+                (3, fix( &textinput.colors[3], &backgrounds[3..] )),
+            ];
+
+            if fixed.iter().any(|(_, color)| color.is_some()) {
+                let mut colors = textinput.colors.clone();
+
+                for (slot, color) in fixed {
+                    if let Some(color) = color {
+                        colors[slot] = color;
+                    }
+                }
+
+                *textinput = Arc::new( crate::TextInput { colors, ..(**textinput).clone() } );
+            }
+        }
+
+        for tooltip in theme.tooltip.values_mut() {
+            if let Some(text) = fix( &tooltip.text, &[&tooltip.background] ) {
+                *tooltip = Arc::new( crate::Tooltip { text, ..(**tooltip).clone() } );
+            }
+        }
+
+        theme
+    }
+}
+
+
+
+/// Returns the text color with its OKLCH lightness changed until its contrast against
+/// every opaque background reaches `target`. Returns `None` if the color already meets it.
+fn adjust(text: &Color, backgrounds: &[Color], target: f32) -> Option<Color> {
+    let contrast = |color: &Color| backgrounds.iter()
+        .map(|background| blend(color, background).contrast(background))
+        .fold(f32::INFINITY, f32::min);
+
+    let mut best = (contrast(text), *text);
+
+    if best.0 >= target {
+        return None;
+    }
+
+    let (lightness, chroma, hue) = text.to_oklch();
+    let alpha = iced::Color::from(*text).a;
+
+    // Try the closest lightness first, alternating between lighter and darker.
+    for step in 1..=STEPS {
+        let delta = step as f32 / STEPS as f32;
+
+        for lightness in [lightness + delta, lightness - delta] {
+            if !(0.0..=1.0).contains(&lightness) {
+                continue;
+            }
+
+            let color = Color::from_oklch(lightness, chroma, hue, alpha).with_notation( text.notation() );
+            let ratio = contrast(&color);
+
+            if ratio >= target {
+                return Some(color);
+            }
+
+            if ratio > best.0 {
+                best = (ratio, color);
+            }
+        }
+    }
+
+    Some(best.1)
+}
+
+/// Returns the fixed version of a shared value, fixing it once for all the entries that share it.
+/// Returns the value itself if `fix` leaves it unchanged.
+fn shared<T>(fixed: &mut HashMap<*const T, Arc<T>>, value: &Arc<T>, fix: impl FnOnce(&T) -> Option<T>) -> Arc<T> {
+    fixed.entry( Arc::as_ptr(value) )
+        .or_insert_with(|| match fix(value) {
+            Some(fixed) => Arc::new(fixed),
+            _ => value.clone(),
+        })
+        .clone()
+}
+
+/// Checks if any of the states was replaced.
+fn changed<T>(states: &[Arc<T>], original: &[Arc<T>]) -> bool {
+    states.iter().zip(original).any(|(state, original)| !Arc::ptr_eq(state, original))
+}
+
+/// Returns the colors a background is drawn with.
+fn colors(background: &Background) -> Vec<Color> {
+    match background {
+        Background::Color(color) => vec![**color],
+        Background::Gradient(gradient) => gradient.stops.iter().map(|(_, color)| **color).collect(),
+    }
+}
+
+/// Blends a color over an opaque background.
+fn blend(color: &Color, background: &Color) -> Color {
+    let (top, bottom) = (iced::Color::from(*color), iced::Color::from(*background));
+
+    let channel = |a: f32, b: f32| ((a * top.a + b * (1.0 - top.a)).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    Color::new( channel(top.r, bottom.r), channel(top.g, bottom.g), channel(top.b, bottom.b), 1.0 )
+}
@@ -0,0 +1,69 @@
+//! Application theme.
+
+
+
+pub(crate) mod serial;
+
+
+
+use crate::{ Color, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
+
+use iced::application::{ Appearance, StyleSheet, };
+
+use std::sync::Arc;
+
+
+
+#[derive(Clone, Debug)]
+pub struct Application {
+    /// Window background color.
+    pub background: Arc<Color>,
+
+    /// Default text color.
+    pub text: Arc<Color>,
+}
+
+impl Application {
+    /// Attempts to create a theme from its serialized version.
+    pub(crate) fn create(serial: &serial::Application, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background color.
+        let background = theme.getcolor(&serial.background, "background", &mut errors);
+
+        // Get the text color.
+        let text = theme.getcolor(&serial.text, "text", &mut errors);
+
+        match (background, text) {
+            (Some(background), Some(text)) => Ok( Application { background, text } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Application {
+        serial::Application { background: export.color(&self.background), text: export.color(&self.text) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Application { background: remap.color(&self.background), text: remap.color(&self.text) }
+    }
+}
+
+impl From<Application> for iced::theme::Application {
+    fn from(application: Application) -> Self {
+        iced::theme::Application::Custom( Box::new(application) )
+    }
+}
+
+impl StyleSheet for Application {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _: &Self::Style) -> Appearance {
+        Appearance {
+            background_color: (*self.background).into(),
+            text_color: (*self.text).into(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! `serde` compatible version of the application theme.
+
+
+
+use crate::serial::ColorRef;
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Application {
+    /// Reference to the window background color.
+    pub background: ColorRef,
+
+    /// Reference to the default text color.
+    pub text: ColorRef,
+}
@@ -0,0 +1,480 @@
+//! `iced` theme backed by a `marcel` `Theme`.
+//! `AppTheme` can be used as the `Theme` of an `iced` `Application`, and
+//! every widget selects its style with a `Style` key into the loaded maps.
+
+
+
+use crate::Theme;
+
+use iced::{
+    application,
+    overlay::menu,
+    widget::{
+        button, checkbox, container, pane_grid, pick_list, progress_bar,
+        radio, rule, scrollable, slider, svg, text, text_input, toggler,
+    },
+};
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::Arc,
+};
+
+
+
+/// Key of a style in the maps of a `Theme`.
+/// The default style selects the default key of the widget.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style(pub Cow<'static, str>);
+
+impl Style {
+    /// Creates a style key from a static string.
+    pub const fn new(key: &'static str) -> Self {
+        Style( Cow::Borrowed(key) )
+    }
+}
+
+impl From<&'static str> for Style {
+    fn from(key: &'static str) -> Self {
+        Style( Cow::Borrowed(key) )
+    }
+}
+
+impl From<String> for Style {
+    fn from(key: String) -> Self {
+        Style( Cow::Owned(key) )
+    }
+}
+
+
+
+/// Default key of each widget, used when a `Style` key is not found.
+#[derive(Clone, Debug)]
+pub struct Defaults {
+    /// Default application key.
+    pub application: String,
+
+    /// Default button key.
+    pub button: String,
+
+    /// Default checkbox key.
+    pub checkbox: String,
+
+    /// Default container key.
+    pub container: String,
+
+    /// Default pane grid key.
+    pub panegrid: String,
+
+    /// Default pick list key. Also used for menus.
+    pub picklist: String,
+
+    /// Default progress bar key.
+    pub progressbar: String,
+
+    /// Default radio key.
+    pub radio: String,
+
+    /// Default rule key.
+    pub rule: String,
+
+    /// Default scrollable key.
+    pub scrollable: String,
+
+    /// Default slider key. Also used for vertical sliders.
+    pub slider: String,
+
+    /// Default svg key.
+    pub svg: String,
+
+    /// Default text key.
+    pub text: String,
+
+    /// Default text input key.
+    pub textinput: String,
+
+    /// Default toggler key.
+    pub toggler: String,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        const KEY: &str = "default";
+
+        Defaults {
+            application: KEY.into(),
+            button: KEY.into(),
+            checkbox: KEY.into(),
+            container: KEY.into(),
+            panegrid: KEY.into(),
+            picklist: KEY.into(),
+            progressbar: KEY.into(),
+            radio: KEY.into(),
+            rule: KEY.into(),
+            scrollable: KEY.into(),
+            slider: KEY.into(),
+            svg: KEY.into(),
+            text: KEY.into(),
+            textinput: KEY.into(),
+            toggler: KEY.into(),
+        }
+    }
+}
+
+
+
+#[derive(Clone, Debug, Default)]
+pub struct AppTheme {
+    /// Loaded theme.
+    pub theme: Arc<Theme>,
+
+    /// Default key of each widget.
+    pub defaults: Defaults,
+
+    /// `iced` theme used when neither the key nor the default key exist.
+    pub fallback: iced::Theme,
+}
+
+impl AppTheme {
+    /// Creates an application theme with the default keys.
+    pub fn new(theme: Theme) -> Self {
+        AppTheme { theme: Arc::new(theme), defaults: Defaults::default(), fallback: iced::Theme::default() }
+    }
+
+    /// Looks up the given style, or the default key if it does not exist.
+    fn get<'a, T>(map: &'a HashMap<String, Arc<T>>, style: &Style, default: &str) -> Option<&'a T> {
+        map.get( &*style.0 )
+            .or_else(|| map.get(default))
+            .map(|t| &**t)
+    }
+}
+
+impl From<Theme> for AppTheme {
+    fn from(theme: Theme) -> Self {
+        Self::new(theme)
+    }
+}
+
+
+
+impl application::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Style) -> application::Appearance {
+        match Self::get(&self.theme.application, style, &self.defaults.application) {
+            Some(a) => a.appearance(&self.fallback),
+            _ => self.fallback.appearance(&iced::theme::Application::default()),
+        }
+    }
+}
+
+impl button::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style) -> button::Appearance {
+        match Self::get(&self.theme.button, style, &self.defaults.button) {
+            Some(b) => b.active(&self.fallback),
+            _ => self.fallback.active(&iced::theme::Button::default()),
+        }
+    }
+
+    fn hovered(&self, style: &Style) -> button::Appearance {
+        match Self::get(&self.theme.button, style, &self.defaults.button) {
+            Some(b) => b.hovered(&self.fallback),
+            _ => self.fallback.hovered(&iced::theme::Button::default()),
+        }
+    }
+
+    fn pressed(&self, style: &Style) -> button::Appearance {
+        match Self::get(&self.theme.button, style, &self.defaults.button) {
+            Some(b) => b.pressed(&self.fallback),
+            _ => self.fallback.pressed(&iced::theme::Button::default()),
+        }
+    }
+
+    fn disabled(&self, style: &Style) -> button::Appearance {
+        match Self::get(&self.theme.button, style, &self.defaults.button) {
+            Some(b) => b.disabled(&self.fallback),
+            _ => self.fallback.disabled(&iced::theme::Button::default()),
+        }
+    }
+}
+
+impl checkbox::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style, checked: bool) -> checkbox::Appearance {
+        match Self::get(&self.theme.checkbox, style, &self.defaults.checkbox) {
+            Some(c) => c.active(&self.fallback, checked),
+            _ => self.fallback.active(&iced::theme::Checkbox::default(), checked),
+        }
+    }
+
+    fn hovered(&self, style: &Style, checked: bool) -> checkbox::Appearance {
+        match Self::get(&self.theme.checkbox, style, &self.defaults.checkbox) {
+            Some(c) => c.hovered(&self.fallback, checked),
+            _ => self.fallback.hovered(&iced::theme::Checkbox::default(), checked),
+        }
+    }
+}
+
+impl container::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Style) -> container::Appearance {
+        // Tooltips are styled as containers, so look them up after the containers.
+        if let Some(t) = self.theme.tooltip.get( &*style.0 ).filter(|_| !self.theme.container.contains_key( &*style.0 )) {
+            return t.appearance(&self.fallback);
+        }
+
+        match Self::get(&self.theme.container, style, &self.defaults.container) {
+            Some(c) => c.appearance(&self.fallback),
+            _ => self.fallback.appearance(&iced::theme::Container::default()),
+        }
+    }
+}
+
+impl menu::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Style) -> menu::Appearance {
+        match Self::get(&self.theme.picklist, style, &self.defaults.picklist) {
+            Some(p) => menu::StyleSheet::appearance(p, &self.fallback),
+            _ => menu::StyleSheet::appearance(&self.fallback, &iced::theme::Menu::default()),
+        }
+    }
+}
+
+impl pane_grid::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn hovered_region(&self, style: &Style) -> pane_grid::Appearance {
+        match Self::get(&self.theme.panegrid, style, &self.defaults.panegrid) {
+            Some(p) => p.hovered_region(&self.fallback),
+            _ => self.fallback.hovered_region(&iced::theme::PaneGrid::default()),
+        }
+    }
+
+    fn picked_split(&self, style: &Style) -> Option<pane_grid::Line> {
+        match Self::get(&self.theme.panegrid, style, &self.defaults.panegrid) {
+            Some(p) => p.picked_split(&self.fallback),
+            _ => self.fallback.picked_split(&iced::theme::PaneGrid::default()),
+        }
+    }
+
+    fn hovered_split(&self, style: &Style) -> Option<pane_grid::Line> {
+        match Self::get(&self.theme.panegrid, style, &self.defaults.panegrid) {
+            Some(p) => p.hovered_split(&self.fallback),
+            _ => self.fallback.hovered_split(&iced::theme::PaneGrid::default()),
+        }
+    }
+}
+
+impl pick_list::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style) -> pick_list::Appearance {
+        match Self::get(&self.theme.picklist, style, &self.defaults.picklist) {
+            Some(p) => pick_list::StyleSheet::active(p, &self.fallback),
+            _ => pick_list::StyleSheet::active(&self.fallback, &iced::theme::PickList::default()),
+        }
+    }
+
+    fn hovered(&self, style: &Style) -> pick_list::Appearance {
+        match Self::get(&self.theme.picklist, style, &self.defaults.picklist) {
+            Some(p) => pick_list::StyleSheet::hovered(p, &self.fallback),
+            _ => pick_list::StyleSheet::hovered(&self.fallback, &iced::theme::PickList::default()),
+        }
+    }
+}
+
+impl progress_bar::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Style) -> progress_bar::Appearance {
+        match Self::get(&self.theme.progressbar, style, &self.defaults.progressbar) {
+            Some(p) => p.appearance(&self.fallback),
+            _ => self.fallback.appearance(&iced::theme::ProgressBar::default()),
+        }
+    }
+}
+
+impl radio::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style, selected: bool) -> radio::Appearance {
+        match Self::get(&self.theme.radio, style, &self.defaults.radio) {
+            Some(r) => r.active(&self.fallback, selected),
+            _ => self.fallback.active(&iced::theme::Radio::default(), selected),
+        }
+    }
+
+    fn hovered(&self, style: &Style, selected: bool) -> radio::Appearance {
+        match Self::get(&self.theme.radio, style, &self.defaults.radio) {
+            Some(r) => r.hovered(&self.fallback, selected),
+            _ => self.fallback.hovered(&iced::theme::Radio::default(), selected),
+        }
+    }
+}
+
+impl rule::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Style) -> rule::Appearance {
+        match Self::get(&self.theme.rule, style, &self.defaults.rule) {
+            Some(r) => r.appearance(&self.fallback),
+            _ => self.fallback.appearance(&iced::theme::Rule::default()),
+        }
+    }
+}
+
+impl scrollable::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style) -> scrollable::Scrollbar {
+        match Self::get(&self.theme.scrollable, style, &self.defaults.scrollable) {
+            Some(s) => s.active(&self.fallback),
+            _ => self.fallback.active(&iced::theme::Scrollable::default()),
+        }
+    }
+
+    fn hovered(&self, style: &Style, over: bool) -> scrollable::Scrollbar {
+        match Self::get(&self.theme.scrollable, style, &self.defaults.scrollable) {
+            Some(s) => s.hovered(&self.fallback, over),
+            _ => self.fallback.hovered(&iced::theme::Scrollable::default(), over),
+        }
+    }
+
+    fn dragging(&self, style: &Style) -> scrollable::Scrollbar {
+        match Self::get(&self.theme.scrollable, style, &self.defaults.scrollable) {
+            Some(s) => s.dragging(&self.fallback),
+            _ => self.fallback.dragging(&iced::theme::Scrollable::default()),
+        }
+    }
+}
+
+impl slider::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style) -> slider::Appearance {
+        match Self::get(&self.theme.slider, style, &self.defaults.slider) {
+            Some(s) => s.active(&self.fallback),
+            _ => self.fallback.active(&iced::theme::Slider::default()),
+        }
+    }
+
+    fn hovered(&self, style: &Style) -> slider::Appearance {
+        match Self::get(&self.theme.slider, style, &self.defaults.slider) {
+            Some(s) => s.hovered(&self.fallback),
+            _ => self.fallback.hovered(&iced::theme::Slider::default()),
+        }
+    }
+
+    fn dragging(&self, style: &Style) -> slider::Appearance {
+        match Self::get(&self.theme.slider, style, &self.defaults.slider) {
+            Some(s) => s.dragging(&self.fallback),
+            _ => self.fallback.dragging(&iced::theme::Slider::default()),
+        }
+    }
+}
+
+impl svg::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn appearance(&self, style: &Style) -> svg::Appearance {
+        match Self::get(&self.theme.svg, style, &self.defaults.svg) {
+            Some(s) => s.appearance(&self.fallback),
+            _ => svg::Appearance::default(),
+        }
+    }
+}
+
+impl text::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn appearance(&self, style: Style) -> text::Appearance {
+        match Self::get(&self.theme.text, &style, &self.defaults.text) {
+            Some(t) => t.appearance(self.fallback.clone()),
+            _ => text::Appearance::default(),
+        }
+    }
+}
+
+impl text_input::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style) -> text_input::Appearance {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.active(&self.fallback),
+            _ => self.fallback.active(&iced::theme::TextInput::default()),
+        }
+    }
+
+    fn focused(&self, style: &Style) -> text_input::Appearance {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.focused(&self.fallback),
+            _ => self.fallback.focused(&iced::theme::TextInput::default()),
+        }
+    }
+
+    fn hovered(&self, style: &Style) -> text_input::Appearance {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.hovered(&self.fallback),
+            _ => self.fallback.hovered(&iced::theme::TextInput::default()),
+        }
+    }
+
+    fn disabled(&self, style: &Style) -> text_input::Appearance {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.disabled(&self.fallback),
+            _ => self.fallback.disabled(&iced::theme::TextInput::default()),
+        }
+    }
+
+    fn placeholder_color(&self, style: &Style) -> iced::Color {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.placeholder_color(&self.fallback),
+            _ => self.fallback.placeholder_color(&iced::theme::TextInput::default()),
+        }
+    }
+
+    fn value_color(&self, style: &Style) -> iced::Color {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.value_color(&self.fallback),
+            _ => self.fallback.value_color(&iced::theme::TextInput::default()),
+        }
+    }
+
+    fn disabled_color(&self, style: &Style) -> iced::Color {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.disabled_color(&self.fallback),
+            _ => self.fallback.disabled_color(&iced::theme::TextInput::default()),
+        }
+    }
+
+    fn selection_color(&self, style: &Style) -> iced::Color {
+        match Self::get(&self.theme.textinput, style, &self.defaults.textinput) {
+            Some(t) => t.selection_color(&self.fallback),
+            _ => self.fallback.selection_color(&iced::theme::TextInput::default()),
+        }
+    }
+}
+
+impl toggler::StyleSheet for AppTheme {
+    type Style = Style;
+
+    fn active(&self, style: &Style, toggled: bool) -> toggler::Appearance {
+        match Self::get(&self.theme.toggler, style, &self.defaults.toggler) {
+            Some(t) => t.active(&self.fallback, toggled),
+            _ => self.fallback.active(&iced::theme::Toggler::default(), toggled),
+        }
+    }
+
+    fn hovered(&self, style: &Style, toggled: bool) -> toggler::Appearance {
+        match Self::get(&self.theme.toggler, style, &self.defaults.toggler) {
+            Some(t) => t.hovered(&self.fallback, toggled),
+            _ => self.fallback.hovered(&iced::theme::Toggler::default(), toggled),
+        }
+    }
+}
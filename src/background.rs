@@ -0,0 +1,46 @@
+//! Background of a widget, which can be a color or a gradient.
+
+
+
+use crate::{ Color, Gradient, };
+
+use std::sync::Arc;
+
+
+
+#[derive(Clone, Debug)]
+pub enum Background {
+    /// Solid color background.
+    Color( Arc<Color> ),
+
+    /// Linear gradient background.
+    Gradient( Arc<Gradient> ),
+}
+
+impl Background {
+    /// Returns the color of the background, if it is not a gradient.
+    pub fn color(&self) -> Option<&Arc<Color>> {
+        match self {
+            Background::Color(color) => Some(color),
+            Background::Gradient(_) => None,
+        }
+    }
+}
+
+impl From<&Background> for iced::Background {
+    fn from(background: &Background) -> Self {
+        match background {
+            Background::Color(color) => (**color).into(),
+            Background::Gradient(gradient) => iced::Background::Gradient( gradient.as_ref().into() ),
+        }
+    }
+}
+
+impl core::fmt::Display for Background {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Background::Color(color) => core::fmt::Display::fmt(color, f),
+            Background::Gradient(gradient) => core::fmt::Display::fmt(gradient, f),
+        }
+    }
+}
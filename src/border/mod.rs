@@ -6,7 +6,7 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Color, Theme };
+use crate::{ Color, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
 
 use iced::BorderRadius;
 
@@ -28,10 +28,22 @@ pub struct Border {
 
 impl Border {
     /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::Border, theme: &Theme) -> Result<Self, ()> {
-        match theme.color.get(&serial.color) {
-            Some(color) => Ok( Self { color: color.clone(), radius: BorderRadius::from( serial.radius ), width: serial.width } ),
-            _ => Err(()),
+    pub(crate) fn create(serial: &serial::Border, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        match theme.getcolor(&serial.color, "color", &mut errors) {
+            Some(color) => Ok( Self { color, radius: serial.radius.convert(), width: serial.width } ),
+            _ => Err(errors),
         }
     }
+
+    /// Exports the border to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Border {
+        serial::Border { color: export.color(&self.color), radius: self.radius.into(), width: self.width }
+    }
+
+    /// Remaps the colors of the border.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Border { color: remap.color(&self.color), radius: self.radius, width: self.width }
+    }
 }
@@ -2,18 +2,132 @@
 
 
 
+use crate::serial::ColorRef;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{ self, SeqAccess, Visitor },
+};
+
 use serde_derive::{ Deserialize, Serialize };
 
+use std::fmt;
+
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Border {
-    /// Key to the border color.
-    pub color: String,
+    /// Reference to the border color.
+    pub color: ColorRef,
 
     /// Radius of the border.
-    pub radius: f32,
+    pub radius: Radius,
 
     /// Width of the border.
     pub width: f32,
 }
+
+
+
+/// Radius of the corners of a widget.
+/// Deserializes from a number for all the corners, or from a list of the
+/// `[top_left, top_right, bottom_right, bottom_left]` corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Radius {
+    /// Same radius on all the corners.
+    Uniform(f32),
+
+    /// Radius of each corner, clockwise from the top left.
+    Corners([f32; 4]),
+}
+
+impl Radius {
+    /// Converts the radius to its `iced` version.
+    pub fn convert(&self) -> iced::BorderRadius {
+        match *self {
+            Radius::Uniform(radius) => radius.into(),
+            Radius::Corners(corners) => corners.into(),
+        }
+    }
+}
+
+impl From<iced::BorderRadius> for Radius {
+    fn from(radius: iced::BorderRadius) -> Self {
+        let corners: [f32; 4] = radius.into();
+
+        match corners.iter().all(|corner| *corner == corners[0]) {
+            true => Radius::Uniform(corners[0]),
+            false => Radius::Corners(corners),
+        }
+    }
+}
+
+impl Serialize for Radius {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self, serializer.is_human_readable()) {
+            (Radius::Uniform(radius), true) => serializer.serialize_f32(*radius),
+            (Radius::Corners(corners), true) => corners.serialize(serializer),
+            (Radius::Uniform(radius), false) => serializer.serialize_newtype_variant("Radius", 0, "Uniform", radius),
+            (Radius::Corners(corners), false) => serializer.serialize_newtype_variant("Radius", 1, "Corners", corners),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Radius {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Binary formats are not self describing and use a tagged enum.
+        if !deserializer.is_human_readable() {
+            return match Tagged::deserialize(deserializer)? {
+                Tagged::Uniform(radius) => Ok( Radius::Uniform(radius) ),
+                Tagged::Corners(corners) => Ok( Radius::Corners(corners) ),
+            };
+        }
+
+        deserializer.deserialize_any(RadiusVisitor)
+    }
+}
+
+/// Radius of non self describing formats.
+#[derive(Deserialize)]
+#[serde(rename = "Radius")]
+enum Tagged {
+    Uniform(f32),
+    Corners([f32; 4]),
+}
+
+/// Visitor of a radius in self describing formats.
+struct RadiusVisitor;
+
+impl<'de> Visitor<'de> for RadiusVisitor {
+    type Value = Radius;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a radius or a list of 4 corner radii")
+    }
+
+    fn visit_f64<E: de::Error>(self, radius: f64) -> Result<Radius, E> {
+        Ok( Radius::Uniform(radius as f32) )
+    }
+
+    fn visit_i64<E: de::Error>(self, radius: i64) -> Result<Radius, E> {
+        Ok( Radius::Uniform(radius as f32) )
+    }
+
+    fn visit_u64<E: de::Error>(self, radius: u64) -> Result<Radius, E> {
+        Ok( Radius::Uniform(radius as f32) )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Radius, A::Error> {
+        let mut corners = [0.0; 4];
+
+        for (i, corner) in corners.iter_mut().enumerate() {
+            *corner = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+
+        if seq.next_element::<f32>()?.is_some() {
+            return Err( de::Error::invalid_length(5, &self) );
+        }
+
+        Ok( Radius::Corners(corners) )
+    }
+}
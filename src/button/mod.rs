@@ -6,7 +6,7 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Border, Color, Theme };
+use crate::{ Background, Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
 
 use iced::{
     Vector,
@@ -18,7 +18,10 @@ use iced::{
 
 use serial::Component;
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
 
 
 #[derive(Clone, Debug)]
@@ -29,45 +32,37 @@ pub struct Button {
 }
 
 impl Button {
-    /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::Button, theme: &Theme) -> Result<Self, ()> {
-        // Get all the themes.
-        let active   = Self::state( &serial.active  , theme, 0 )?;
-        let hovered  = Self::state( &serial.hovered , theme, 1 )?;
-        let pressed  = Self::state( &serial.pressed , theme, 2 )?;
-        let disabled = Self::state( &serial.disabled, theme, 3 )?;
-        
-        // Find the first state theme that is not None.
-        let default = match (&active, &hovered, &pressed, &disabled) {
-            (Some(d), _, _, _) => d.clone(),
-            (_, Some(d), _, _) => d.clone(),
-            (_, _, Some(d), _) => d.clone(),
-            (_, _, _, Some(d)) => d.clone(),
-
-            _ => return Err(()),
-        };
-
-        Ok(Button {
-            state: [
-                active.unwrap_or(default.clone()),
-                hovered.unwrap_or(default.clone()),
-                pressed.unwrap_or(default.clone()),
-                disabled.unwrap_or(default.clone()),
-            ],
-        })
+    /// Names of the button states, in order.
+    pub(crate) const STATES: [&'static str; 4] = [ "active", "hovered", "pressed", "disabled", ];
+
+    /// Collects the states of the serialized buttons for inheritance resolution.
+    pub(crate) fn group(serial: &HashMap<String, serial::Button>) -> Group<'_, Component, State> {
+        let mut group = Group::new( "button", &Self::STATES, State::from );
+
+        for (name, button) in serial {
+            group.insert( name, vec![ &button.active, &button.hovered, &button.pressed, &button.disabled ] );
+        }
+
+        group
     }
 
-    fn state(serial: &Component, theme: &Theme, index: usize) -> Result<Option<Arc<State>>, ()> {
-        match serial {
-            Component::Defined( state ) => Ok( Some( Arc::new( State::from(&state, &theme)? ) ) ),
+    /// Creates a theme from its resolved states.
+    pub(crate) fn create(name: &str, group: &Group<Component, State>) -> Option<Self> {
+        let state = group.get(name)?;
 
-            Component::Inherited( name ) => match theme.button.get( name ) {
-                Some( button ) => Ok( Some( button.state[index].clone() ) ),
-                _ => Err(()),
-            },
+        Some( Button { state: state.try_into().ok()? } )
+    }
 
-            Component::None => Ok( None ),
-        }
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Button {
+        let state = |i: usize| serial::Component::Defined( self.state[i].serial(export) );
+
+        serial::Button { active: state(0), hovered: state(1), pressed: state(2), disabled: state(3) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Button { state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)) }
     }
 }
 
@@ -76,8 +71,8 @@ impl StyleSheet for Button {
 
     fn active(&self, _: &Self::Style) -> Appearance {
         Appearance {
-            shadow_offset: Vector::new(0.0, 0.0),
-            background: Some( (*self.state[0].background).into() ),
+            shadow_offset: self.state[0].shadow,
+            background: Some( (&self.state[0].background).into() ),
             border_radius: self.state[0].border.radius,
             border_width: self.state[0].border.width,
             border_color: (*self.state[0].border.color).into(),
@@ -87,8 +82,8 @@ impl StyleSheet for Button {
 
     fn hovered(&self, _: &Self::Style) -> Appearance {
         Appearance {
-            shadow_offset: Vector::new(0.0, 0.0),
-            background: Some( (*self.state[1].background).into() ),
+            shadow_offset: self.state[1].shadow,
+            background: Some( (&self.state[1].background).into() ),
             border_radius: self.state[1].border.radius,
             border_width: self.state[1].border.width,
             border_color: (*self.state[1].border.color).into(),
@@ -98,8 +93,8 @@ impl StyleSheet for Button {
 
     fn pressed(&self, _: &Self::Style) -> Appearance {
         Appearance {
-            shadow_offset: Vector::new(0.0, 0.0),
-            background: Some( (*self.state[2].background).into() ),
+            shadow_offset: self.state[2].shadow,
+            background: Some( (&self.state[2].background).into() ),
             border_radius: self.state[2].border.radius,
             border_width: self.state[2].border.width,
             border_color: (*self.state[2].border.color).into(),
@@ -109,8 +104,8 @@ impl StyleSheet for Button {
 
     fn disabled(&self, _: &Self::Style) -> Appearance {
         Appearance {
-            shadow_offset: Vector::new(0.0, 0.0),
-            background: Some( (*self.state[3].background).into() ),
+            shadow_offset: self.state[3].shadow,
+            background: Some( (&self.state[3].background).into() ),
             border_radius: self.state[3].border.radius,
             border_width: self.state[3].border.width,
             border_color: (*self.state[3].border.color).into(),
@@ -123,37 +118,56 @@ impl StyleSheet for Button {
 
 #[derive(Clone, Debug)]
 pub struct State {
-    /// Background color.
-    pub(crate) background: Arc<Color>,
+    /// Background color or gradient.
+    pub(crate) background: Background,
 
     /// Text color.
     pub(crate) text: Arc<Color>,
 
     /// Border theme.
     pub(crate) border: Arc<Border>,
+
+    /// Offset of the shadow.
+    pub(crate) shadow: Vector,
 }
 
 impl State {
     /// Attempts to create a theme from its serialized version.
-    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, ()> {
-        // Get the background color.
-        let background = match theme.color.get(&serial.background) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background color or gradient.
+        let background = theme.getbackground(&serial.background, "background", &mut errors);
 
         // Get the text color.
-        let text = match theme.color.get(&serial.text) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        // Get the background color.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
-
-        Ok( State { background, text, border } )
+        let text = theme.getcolor(&serial.text, "text", &mut errors);
+
+        // Get the border.
+        let border = theme.getborder(&serial.border, "border", &mut errors);
+
+        match (background, text, border) {
+            (Some(background), Some(text), Some(border)) => Ok( State { background, text, border, shadow: Vector::new(serial.shadow.0, serial.shadow.1) } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            background: export.background(&self.background),
+            text: export.color(&self.text),
+            border: export.border(&self.border),
+            shadow: (self.shadow.x, self.shadow.y),
+        }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            background: remap.background(&self.background),
+            text: remap.color(&self.text),
+            border: remap.border(&self.border),
+            shadow: self.shadow,
+        }
     }
 }
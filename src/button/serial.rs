@@ -2,6 +2,9 @@
 
 
 
+use crate::serial::{ BackgroundRef, BorderRef, ColorRef };
+use crate::theme::graph::Link;
+
 use serde_derive::{
     Deserialize, Serialize,
 };
@@ -27,14 +30,18 @@ pub struct Button {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct State {
-    /// Key to the background color.
-    pub(super) background: String,
+    /// Reference to the background color or gradient.
+    pub(super) background: BackgroundRef,
+
+    /// Reference to the text color.
+    pub(super) text: ColorRef,
 
-    /// Key to the text color.
-    pub(super) text: String,
+    /// Reference to the border theme.
+    pub(super) border: BorderRef,
 
-    /// Key to the border theme.
-    pub(super) border: String,
+    /// Offset of the shadow. Defaults to no shadow.
+    #[serde(default)]
+    pub(super) shadow: (f32, f32),
 }
 
 
@@ -50,3 +57,15 @@ pub enum Component {
     /// The theme is not defined.
     None,
 }
+
+impl crate::theme::graph::Component for Component {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            Component::Defined( state ) => Link::Defined( state ),
+            Component::Inherited( name ) => Link::Inherited( name ),
+            Component::None => Link::None,
+        }
+    }
+}
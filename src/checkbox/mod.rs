@@ -2,15 +2,155 @@
 
 
 
-mod serial;
-mod style;
+pub(crate) mod serial;
 
 
 
-pub use self::serial::{
-    Checkbox as Serial,
-    CheckboxState as StateSerial,
-    Component,
+use crate::{ Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
+
+use iced::widget::checkbox::{ Appearance, StyleSheet, };
+
+use serial::Component;
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
 };
 
-pub use self::style::Theme;
+
+
+#[derive(Clone, Debug)]
+pub struct Checkbox {
+    /// State Themes of the checkbox.
+    /// In order: active, hovered, active unchecked, hovered unchecked.
+    pub state: [Arc<State>; 4],
+}
+
+impl Checkbox {
+    /// Names of the checkbox states, in order.
+    pub(crate) const STATES: [&'static str; 4] = [ "active", "hovered", "uactive", "uhovered", ];
+
+    /// States the unchecked states fall back on: `uhovered` on `uactive` and `uactive` on `active`.
+    const FALLBACKS: [Option<usize>; 4] = [ None, None, Some(0), Some(2), ];
+
+    /// Collects the states of the serialized checkboxes for inheritance resolution.
+    pub(crate) fn group(serial: &HashMap<String, serial::Checkbox>) -> Group<'_, Component, State> {
+        let mut group = Group::new( "checkbox", &Self::STATES, State::from ).fallbacks( &Self::FALLBACKS );
+
+        for (name, checkbox) in serial {
+            group.insert( name, vec![ &checkbox.active, &checkbox.hovered, &checkbox.uactive, &checkbox.uhovered ] );
+        }
+
+        group
+    }
+
+    /// Creates a theme from its resolved states.
+    pub(crate) fn create(name: &str, group: &Group<Component, State>) -> Option<Self> {
+        let state = group.get(name)?;
+
+        Some( Checkbox { state: state.try_into().ok()? } )
+    }
+
+    /// Builds the appearance of the given state.
+    fn appearance(&self, index: usize) -> Appearance {
+        Appearance {
+            background: (*self.state[index].background).into(),
+            icon_color: (*self.state[index].checkmark).into(),
+            border_radius: self.state[index].border.radius,
+            border_width: self.state[index].border.width,
+            border_color: (*self.state[index].border.color).into(),
+            text_color: self.state[index].text.as_ref().map(|c| (**c).into()),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Checkbox {
+        let state = |i: usize| serial::Component::Defined( self.state[i].serial(export) );
+
+        serial::Checkbox { active: state(0), hovered: state(1), uactive: state(2), uhovered: state(3) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Checkbox { state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)) }
+    }
+}
+
+impl StyleSheet for Checkbox {
+    type Style = iced::Theme;
+
+    fn active(&self, _: &Self::Style, checked: bool) -> Appearance {
+        match checked {
+            true => self.appearance(0),
+            false => self.appearance(2),
+        }
+    }
+
+    fn hovered(&self, _: &Self::Style, checked: bool) -> Appearance {
+        match checked {
+            true => self.appearance(1),
+            false => self.appearance(3),
+        }
+    }
+}
+
+
+
+#[derive(Clone, Debug)]
+pub struct State {
+    /// Background color.
+    pub(crate) background: Arc<Color>,
+
+    /// Checkmark color.
+    pub(crate) checkmark: Arc<Color>,
+
+    /// Text color. Uses the default text color if not set.
+    pub(crate) text: Option<Arc<Color>>,
+
+    /// Border theme.
+    pub(crate) border: Arc<Border>,
+}
+
+impl State {
+    /// Attempts to create a theme from its serialized version.
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background color.
+        let background = theme.getcolor(&serial.background, "background", &mut errors);
+
+        // Get the checkmark color.
+        let checkmark = theme.getcolor(&serial.checkmark, "checkmark", &mut errors);
+
+        // Get the text color, if any.
+        let text = serial.textcolor.as_ref().map(|key| theme.getcolor(key, "textcolor", &mut errors));
+
+        // Get the border.
+        let border = theme.getborder(&serial.border, "border", &mut errors);
+
+        match (background, checkmark, border) {
+            (Some(background), Some(checkmark), Some(border)) if errors.is_empty() => Ok( State { background, checkmark, text: text.flatten(), border } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            background: export.color(&self.background),
+            checkmark: export.color(&self.checkmark),
+            textcolor: self.text.as_ref().map(|text| export.color(text)),
+            border: export.border(&self.border),
+        }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            background: remap.color(&self.background),
+            checkmark: remap.color(&self.checkmark),
+            text: self.text.as_ref().map(|text| remap.color(text)),
+            border: remap.border(&self.border),
+        }
+    }
+}
@@ -2,43 +2,64 @@
 
 
 
+use crate::serial::{ BorderRef, ColorRef };
+use crate::theme::graph::Link;
+
 use serde_derive::{ Deserialize, Serialize };
 
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Checkbox {
-    /// Active state.
+    /// Active checked state.
     pub active: Component,
 
-    /// Hovered state.
+    /// Hovered checked state.
     pub hovered: Component,
+
+    /// Active unchecked state.
+    pub uactive: Component,
+
+    /// Hovered unchecked state.
+    pub uhovered: Component,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct CheckboxState {
-    /// Key to the background color.
-    pub background: String,
+pub struct State {
+    /// Reference to the background color.
+    pub background: ColorRef,
 
-    /// Key to the checkmark color.
-    pub checkmark: String,
+    /// Reference to the checkmark color.
+    pub checkmark: ColorRef,
 
-    /// Key to the text color.
-    pub textcolor: Option<String>,
+    /// Reference to the text color.
+    pub textcolor: Option<ColorRef>,
 
-    /// Key to the border theme.
-    pub border: String,
+    /// Reference to the border theme.
+    pub border: BorderRef,
 }
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Component {
-    /// The button state is defined.
-    Defined(CheckboxState),
+    /// The checkbox state is defined.
+    Defined(State),
 
-    /// The button state is inherited from another button theme.
+    /// The checkbox state is inherited from another checkbox theme.
     Inherited(String),
 
-    /// The button state is not defined.
+    /// The checkbox state is not defined.
     None,
 }
+
+impl crate::theme::graph::Component for Component {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            Component::Defined( state ) => Link::Defined( state ),
+            Component::Inherited( name ) => Link::Inherited( name ),
+            Component::None => Link::None,
+        }
+    }
+}
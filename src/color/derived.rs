@@ -0,0 +1,160 @@
+//! Colors derived from other colors of the theme.
+//! A derived color is written as an expression over color keys, such as
+//! `"lighten(accent, 0.1)"` or `"mix(background, text, 0.2)"`.
+
+
+
+use super::{ Color, InvalidColor, notation::{ byte, hsl_to_rgb, rgb_to_hsl } };
+
+use std::{ fmt, str::FromStr };
+
+
+
+/// Names of the operations of a derived color.
+const OPERATIONS: [&str; 5] = ["lighten", "darken", "mix", "alpha", "saturate"];
+
+
+
+/// Expression of a color over other color keys.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Derived {
+    /// Increases the lightness of a color by an amount in `[-1, 1]`.
+    Lighten(String, f32),
+
+    /// Decreases the lightness of a color by an amount in `[-1, 1]`.
+    Darken(String, f32),
+
+    /// Mixes two colors. A weight of 0 is the first color and 1 the second.
+    Mix(String, String, f32),
+
+    /// Replaces the alpha of a color.
+    Alpha(String, f32),
+
+    /// Increases the saturation of a color by an amount in `[-1, 1]`.
+    Saturate(String, f32),
+}
+
+impl Derived {
+    /// Returns the keys of the colors this color is derived from.
+    pub fn keys(&self) -> Vec<&str> {
+        match self {
+            Derived::Mix(a, b, _) => vec![a, b],
+
+            Derived::Lighten(key, _) | Derived::Darken(key, _) | Derived::Alpha(key, _) | Derived::Saturate(key, _) => vec![key],
+        }
+    }
+
+    /// Computes the color from the colors it is derived from.
+    /// `get` must return the color of every key of the expression.
+    pub(crate) fn apply<'a>(&self, get: impl Fn(&str) -> &'a Color) -> Color {
+        match self {
+            Derived::Lighten(key, amount) => get(key).lighten(*amount),
+            Derived::Darken(key, amount) => get(key).darken(*amount),
+            Derived::Mix(a, b, weight) => get(a).mix(get(b), *weight),
+            Derived::Alpha(key, alpha) => get(key).with_alpha(*alpha),
+            Derived::Saturate(key, amount) => get(key).saturate(*amount),
+        }
+    }
+
+    /// Checks if the string is an expression rather than a color.
+    pub(crate) fn is_expression(string: &str) -> bool {
+        match string.split_once('(') {
+            Some((operation, _)) => OPERATIONS.contains( &operation.trim().to_ascii_lowercase().as_str() ),
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Derived {
+    type Err = InvalidColor;
+
+    /// Parses an expression such as `lighten(accent, 0.1)`.
+    /// The keys can optionally be quoted.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidColor(string.trim().into());
+
+        let (operation, args) = string.trim().split_once('(').ok_or_else(invalid)?;
+        let args = args.strip_suffix(')').ok_or_else(invalid)?;
+        let args: Vec<&str> = args.split(',').map(str::trim).collect();
+
+        let key = |arg: &str| {
+            let key = arg.trim_matches(|c| c == '"' || c == '\'');
+
+            match key.is_empty() {
+                true => Err( invalid() ),
+                false => Ok( key.to_string() ),
+            }
+        };
+
+        let number = |arg: &str, range: (f32, f32)| match arg.parse::<f32>() {
+            Ok(n) if (range.0..=range.1).contains(&n) => Ok(n),
+            _ => Err( invalid() ),
+        };
+
+        match (operation.trim().to_ascii_lowercase().as_str(), &args[..]) {
+            ("lighten", [c, n]) => Ok( Derived::Lighten(key(c)?, number(n, (-1.0, 1.0))?) ),
+            ("darken", [c, n]) => Ok( Derived::Darken(key(c)?, number(n, (-1.0, 1.0))?) ),
+            ("mix", [a, b, n]) => Ok( Derived::Mix(key(a)?, key(b)?, number(n, (0.0, 1.0))?) ),
+            ("alpha", [c, n]) => Ok( Derived::Alpha(key(c)?, number(n, (0.0, 1.0))?) ),
+            ("saturate", [c, n]) => Ok( Derived::Saturate(key(c)?, number(n, (-1.0, 1.0))?) ),
+            _ => Err( invalid() ),
+        }
+    }
+}
+
+impl fmt::Display for Derived {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Derived::Lighten(key, amount) => write!(f, "lighten({}, {})", key, amount),
+            Derived::Darken(key, amount) => write!(f, "darken({}, {})", key, amount),
+            Derived::Mix(a, b, weight) => write!(f, "mix({}, {}, {})", a, b, weight),
+            Derived::Alpha(key, alpha) => write!(f, "alpha({}, {})", key, alpha),
+            Derived::Saturate(key, amount) => write!(f, "saturate({}, {})", key, amount),
+        }
+    }
+}
+
+
+
+impl Color {
+    /// Returns this color with its HSL lightness increased by `amount`.
+    pub fn lighten(&self, amount: f32) -> Self {
+        let (h, s, l) = rgb_to_hsl(self.0, self.1, self.2);
+        let (r, g, b) = hsl_to_rgb(h, s, (l + amount).clamp(0.0, 1.0));
+
+        Color(r, g, b, self.3, self.4)
+    }
+
+    /// Returns this color with its HSL lightness decreased by `amount`.
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns this color with its HSL saturation increased by `amount`.
+    /// A negative amount desaturates the color.
+    pub fn saturate(&self, amount: f32) -> Self {
+        let (h, s, l) = rgb_to_hsl(self.0, self.1, self.2);
+        let (r, g, b) = hsl_to_rgb(h, (s + amount).clamp(0.0, 1.0), l);
+
+        Color(r, g, b, self.3, self.4)
+    }
+
+    /// Mixes this color with `other`. A weight of 0 returns this color and 1 returns `other`.
+    pub fn mix(&self, other: &Color, weight: f32) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| byte( (a as f32 * (1.0 - weight) + b as f32 * weight) / 255.0 );
+
+        Color(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+            self.3 * (1.0 - weight) + other.3 * weight,
+            self.4,
+        )
+    }
+
+    /// Returns this color with the given alpha.
+    pub fn with_alpha(&self, alpha: f32) -> Self {
+        Color(self.0, self.1, self.2, alpha.clamp(0.0, 1.0), self.4)
+    }
+}
@@ -0,0 +1,228 @@
+//! Serialized color.
+
+
+
+pub(crate) mod serial;
+
+mod derived;
+mod names;
+mod notation;
+mod oklch;
+
+
+
+pub use derived::Derived;
+pub use notation::{ InvalidColor, Notation };
+
+
+
+use crate::error::{ ErrorKind, ParseError };
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+
+
+/// RGBA color, with the notation it is serialized with.
+#[derive(Clone, Copy)]
+pub struct Color(u8, u8, u8, f32, Notation);
+
+impl Color {
+    /// Default color black.
+    pub const BLACK: Color = Color(0, 0, 0, 1.0, Notation::Tuple);
+
+    /// Default color red.
+    pub const RED: Color = Color(255, 0, 0, 1.0, Notation::Tuple);
+
+    /// Default color blue.
+    pub const BLUE: Color = Color(0, 0, 255, 1.0, Notation::Tuple);
+
+    /// Default color white.
+    pub const WHITE: Color = Color(255, 255, 255, 1.0, Notation::Tuple);
+}
+
+
+
+impl Color {
+    /// Creates a new color.
+    pub const fn new(r: u8, g: u8, b: u8, a: f32) -> Self {
+        Color( r, g, b, a, Notation::Tuple )
+    }
+
+    /// Returns the notation this color is serialized with.
+    pub const fn notation(&self) -> Notation {
+        self.4
+    }
+
+    /// Returns this color, serialized with the given notation.
+    pub const fn with_notation(self, notation: Notation) -> Self {
+        Color( self.0, self.1, self.2, self.3, notation )
+    }
+}
+
+/// Resolves the color entries of a theme.
+/// Derived colors are computed after the colors they depend on. Colors that
+/// depend on a missing key or on themselves are reported, while the colors
+/// that depend on a failed color fail silently.
+pub(crate) fn resolve(entries: &HashMap<String, serial::Color>) -> (HashMap<String, Arc<Color>>, Vec<ParseError>) {
+    let mut colors = HashMap::new();
+    let mut errors = Vec::new();
+
+    // Colors defined in place do not depend on anything.
+    for (key, entry) in entries {
+        if let serial::Color::Value(color) = entry {
+            colors.insert( key.clone(), Arc::new(*color) );
+        }
+    }
+
+    // Keys of the derived colors, in a stable order.
+    let mut derived: Vec<&str> = entries.iter()
+        .filter(|(_, entry)| matches!(entry, serial::Color::Derived(_)))
+        .map(|(key, _)| key.as_str())
+        .collect();
+
+    derived.sort_unstable();
+
+    let mut failed: Vec<&str> = Vec::new();
+
+    for root in derived {
+        if colors.contains_key(root) || failed.contains(&root) {
+            continue;
+        }
+
+        // Depth first search with an explicit stack, so chains have no depth limit.
+        let mut stack = vec![root];
+
+        while let Some(&key) = stack.last() {
+            let expression = match &entries[key] {
+                serial::Color::Derived(expression) => expression,
+                _ => unreachable!("only derived colors are pushed to the stack"),
+            };
+
+            // First dependency that is not resolved yet.
+            let mut next = None;
+            let mut fail = false;
+
+            for dep in expression.keys() {
+                if colors.contains_key(dep) {
+                    continue;
+                }
+
+                if failed.contains(&dep) {
+                    fail = true;
+                } else if !entries.contains_key(dep) {
+                    errors.push( ParseError::new("", ErrorKind::MissingColor(dep.into())).locate("color", key) );
+                    fail = true;
+                } else if let Some(start) = stack.iter().position(|k| *k == dep) {
+                    let mut path: Vec<String> = stack[start..].iter().map(|k| k.to_string()).collect();
+                    path.push( dep.into() );
+
+                    errors.push( ParseError::new("", ErrorKind::CyclicColor(path)).locate("color", dep) );
+                    failed.extend( &stack[start..] );
+                    fail = true;
+                } else {
+                    next = Some(dep);
+                }
+
+                break;
+            }
+
+            match (fail, next) {
+                (true, _) => failed.push(key),
+
+                (_, Some(dep)) => {
+                    stack.push(dep);
+                    continue;
+                },
+
+                _ => {
+                    let color = expression.apply(|k| &colors[k]);
+                    colors.insert( key.to_string(), Arc::new(color) );
+                },
+            }
+
+            stack.pop();
+        }
+    }
+
+    (colors, errors)
+}
+
+
+
+impl From<Color> for iced::Color {
+    fn from(color: Color) -> Self {
+        let Color(r, g, b, a, _) = color;
+
+        iced::Color::from_rgba8(r, g, b, a)
+    }
+}
+
+impl From<&Color> for iced::Color {
+    fn from(color: &Color) -> Self {
+        iced::Color::from_rgba8(color.0, color.1, color.2, color.3)
+    }
+}
+
+impl From<Color> for iced::theme::Text {
+    fn from(color: Color) -> Self {
+        iced::theme::Text::Color(color.into())
+    }
+}
+
+impl From<&Color> for iced::theme::Text {
+    fn from(color: &Color) -> Self {
+        iced::theme::Text::Color(color.into())
+    }
+}
+
+impl From<Color> for iced::Background {
+    fn from(color: Color) -> Self {
+        iced::Background::Color(color.into())
+    }
+}
+
+impl From<&Color> for iced::Background {
+    fn from(color: &Color) -> Self {
+        iced::Background::Color(color.into())
+    }
+}
+
+
+impl iced::widget::text::StyleSheet for Color {
+    type Style = Self;
+
+    fn appearance(&self, _: Self::Style) -> iced::widget::text::Appearance {
+        iced::widget::text::Appearance { color: Some( self.into() ) }
+    }
+}
+
+impl core::fmt::Debug for Color {
+    /// Formats the channels of the color. The notation is left out, as it
+    /// does not change the color.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_tuple("Color").field(&self.0).field(&self.1).field(&self.2).field(&self.3).finish()
+    }
+}
+
+impl PartialEq for Color {
+    /// Compares the channels of the colors. The notation is left out, as it
+    /// does not change the color.
+    fn eq(&self, other: &Self) -> bool {
+        (self.0, self.1, self.2, self.3) == (other.0, other.1, other.2, other.3)
+    }
+}
+
+impl core::fmt::Display for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str( &format!("R: {:>3} | G: {:>3} | B: {:>3} | A: {:.3}", self.0, self.1, self.2, self.3) )
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::BLACK
+    }
+}
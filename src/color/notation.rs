@@ -0,0 +1,361 @@
+//! Notations of a color and its serialization.
+//! A color can be deserialized from a tuple `Color(r, g, b, a)`, a float
+//! structure `Rgba(r, g, b, a)`, or a string in hex, HSL(A) or CSS name form.
+//! It is serialized with its `Notation`.
+
+
+
+use super::{ Color, names };
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{ self, MapAccess, SeqAccess, Visitor },
+    ser::{ SerializeStruct, SerializeTupleStruct },
+};
+
+use serde_derive::Deserialize;
+
+use std::{ fmt, str::FromStr };
+
+
+
+/// Notation used to serialize a color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Tuple of RGB bytes and float alpha: `Color(255, 0, 0, 1.0)`.
+    #[default]
+    Tuple,
+
+    /// Hex string: `"#ff0000"`, or `"#ff000080"` if not opaque.
+    Hex,
+
+    /// HSL string: `"hsl(0, 100%, 50%)"`, or `"hsla(0, 100%, 50%, 0.5)"` if not opaque.
+    Hsl,
+
+    /// Structure of float channels: `Rgba(r: 1.0, g: 0.0, b: 0.0, a: 1.0)`.
+    Rgba,
+
+    /// CSS color name: `"red"`. Colors without a name are written as `Hex`.
+    Name,
+}
+
+
+
+/// Error returned when a string is not a valid color.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidColor(pub String);
+
+impl fmt::Display for InvalidColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid color `{}`", self.0)
+    }
+}
+
+impl std::error::Error for InvalidColor {}
+
+
+
+impl Color {
+    /// Returns the hex notation of the color (`#rrggbb` or `#rrggbbaa`).
+    pub fn to_hex(&self) -> String {
+        match self.3 >= 1.0 {
+            true => format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2),
+            false => format!("#{:02x}{:02x}{:02x}{:02x}", self.0, self.1, self.2, byte(self.3)),
+        }
+    }
+
+    /// Returns the HSL notation of the color (`hsl(h, s%, l%)` or `hsla(h, s%, l%, a)`).
+    pub fn to_hsl(&self) -> String {
+        let (h, s, l) = rgb_to_hsl(self.0, self.1, self.2);
+        let (h, s, l) = (round(h, 2), round(s * 100.0, 2), round(l * 100.0, 2));
+
+        match self.3 >= 1.0 {
+            true => format!("hsl({}, {}%, {}%)", h, s, l),
+            false => format!("hsla({}, {}%, {}%, {})", h, s, l, round(self.3, 3)),
+        }
+    }
+
+    /// Returns the CSS name of the color, if it has one.
+    pub fn name(&self) -> Option<&'static str> {
+        match (self.0, self.1, self.2, self.3) {
+            (0, 0, 0, 0.0) => Some("transparent"),
+            (r, g, b, a) if a >= 1.0 => names::find(r, g, b),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidColor;
+
+    /// Parses a hex (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`), HSL(A) or CSS name string.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        let lower = string.to_ascii_lowercase();
+
+        let color = match lower.strip_prefix('#') {
+            Some(digits) => hex(digits),
+
+            _ if lower.starts_with("hsl") => hsl(&lower),
+
+            _ if lower == "transparent" => Some( Color(0, 0, 0, 0.0, Notation::Name) ),
+
+            _ => names::get(&lower).map(|(r, g, b)| Color(r, g, b, 1.0, Notation::Name)),
+        };
+
+        color.ok_or_else(|| InvalidColor(string.into()))
+    }
+}
+
+
+
+/// Parses the digits of a hex color.
+fn hex(digits: &str) -> Option<Color> {
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    // Width of each channel. Short channels are repeated (`f` is `ff`).
+    let n = match digits.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+
+    let channel = |i: usize| {
+        let value = u8::from_str_radix(&digits[i * n..(i + 1) * n], 16).ok()?;
+
+        match n {
+            1 => Some(value * 17),
+            _ => Some(value),
+        }
+    };
+
+    let a = match digits.len() / n {
+        4 => channel(3)? as f32 / 255.0,
+        _ => 1.0,
+    };
+
+    Some( Color(channel(0)?, channel(1)?, channel(2)?, a, Notation::Hex) )
+}
+
+/// Parses a `hsl(...)` or `hsla(...)` color.
+/// Arguments can be separated by commas, spaces or a slash before the alpha.
+fn hsl(string: &str) -> Option<Color> {
+    let args = string.strip_prefix("hsla").or_else(|| string.strip_prefix("hsl"))?
+        .trim()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+
+    let args: Vec<&str> = args.split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|arg| !arg.is_empty())
+        .collect();
+
+    let (h, s, l, a) = match args[..] {
+        [h, s, l] => (h, s, l, None),
+        [h, s, l, a] => (h, s, l, Some(a)),
+        _ => return None,
+    };
+
+    let h: f32 = h.strip_suffix("deg").unwrap_or(h).parse().ok()?;
+    let s = percent(s)?;
+    let l = percent(l)?;
+
+    let a = match a {
+        Some(a) => match a.strip_suffix('%') {
+            Some(_) => percent(a)?,
+            _ => a.parse().ok()?,
+        },
+
+        _ => 1.0,
+    };
+
+    if !h.is_finite() || ![s, l, a].iter().all(|v| (0.0..=1.0).contains(v)) {
+        return None;
+    }
+
+    let (r, g, b) = hsl_to_rgb(h, s, l);
+
+    Some( Color(r, g, b, a, Notation::Hsl) )
+}
+
+/// Parses a percentage, with or without the `%` sign, into the range `[0, 1]`.
+fn percent(string: &str) -> Option<f32> {
+    string.strip_suffix('%').unwrap_or(string).parse::<f32>().ok().map(|v| v / 100.0)
+}
+
+
+
+/// Converts a hue in degrees and a saturation and lightness in `[0, 1]` to RGB.
+pub(crate) fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
+    let h = h.rem_euclid(360.0) / 60.0;
+
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+
+    let (r, g, b) = match h as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    (byte(r + m), byte(g + m), byte(b + m))
+}
+
+/// Converts RGB to a hue in degrees and a saturation and lightness in `[0, 1]`.
+pub(crate) fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+
+    let l = (max + min) / 2.0;
+    let d = max - min;
+
+    if d == 0.0 {
+        return (0.0, 0.0, l);
+    }
+
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+
+    (h * 60.0, s, l)
+}
+
+/// Converts a channel in `[0, 1]` to a byte.
+pub(crate) fn byte(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Rounds a value to the given number of decimals.
+fn round(value: f32, decimals: i32) -> f32 {
+    let scale = 10f32.powi(decimals);
+    (value * scale).round() / scale
+}
+
+
+
+/// Names of the fields of the `Rgba` notation.
+const RGBA: [&str; 4] = ["r", "g", "b", "a"];
+
+/// Field of the `Rgba` notation.
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    R,
+    G,
+    B,
+    A,
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Binary formats always use the tuple.
+        let notation = match serializer.is_human_readable() {
+            true => self.4,
+            false => Notation::Tuple,
+        };
+
+        match notation {
+            Notation::Tuple => {
+                let mut tuple = serializer.serialize_tuple_struct("Color", 4)?;
+                tuple.serialize_field(&self.0)?;
+                tuple.serialize_field(&self.1)?;
+                tuple.serialize_field(&self.2)?;
+                tuple.serialize_field(&self.3)?;
+                tuple.end()
+            },
+
+            Notation::Hex => serializer.serialize_str( &self.to_hex() ),
+
+            Notation::Hsl => serializer.serialize_str( &self.to_hsl() ),
+
+            Notation::Rgba => {
+                let channels = [self.0 as f32 / 255.0, self.1 as f32 / 255.0, self.2 as f32 / 255.0, self.3];
+
+                let mut rgba = serializer.serialize_struct("Rgba", 4)?;
+
+                for (field, value) in RGBA.iter().zip(channels.iter()) {
+                    rgba.serialize_field(field, value)?;
+                }
+
+                rgba.end()
+            },
+
+            Notation::Name => match self.name() {
+                Some(name) => serializer.serialize_str(name),
+                _ => serializer.serialize_str( &self.to_hex() ),
+            },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Binary formats are not self describing and always use the tuple.
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(ColorVisitor),
+            false => deserializer.deserialize_tuple_struct("Color", 4, ColorVisitor),
+        }
+    }
+}
+
+/// Visitor of all the notations of a color.
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a color tuple, an RGBA structure or a hex, HSL or CSS name string")
+    }
+
+    fn visit_str<E: de::Error>(self, string: &str) -> Result<Color, E> {
+        string.parse().map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+        let r = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let g = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let b = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let a = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+
+        Ok( Color(r, g, b, a, Notation::Tuple) )
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Color, A::Error> {
+        let mut channels = [None; 4];
+
+        while let Some(field) = map.next_key::<Field>()? {
+            let i = field as usize;
+
+            if channels[i].is_some() {
+                return Err( de::Error::duplicate_field(RGBA[i]) );
+            }
+
+            let value: f32 = map.next_value()?;
+
+            if !(0.0..=1.0).contains(&value) {
+                return Err( de::Error::custom( format!("channel `{}` is {}, outside of [0, 1]", RGBA[i], value) ) );
+            }
+
+            channels[i] = Some(value);
+        }
+
+        let channel = |i: usize| channels[i].ok_or_else(|| de::Error::missing_field(RGBA[i]));
+
+        // The alpha channel is optional.
+        Ok( Color(byte(channel(0)?), byte(channel(1)?), byte(channel(2)?), channels[3].unwrap_or(1.0), Notation::Rgba) )
+    }
+}
@@ -0,0 +1,58 @@
+//! Conversion of colors from and to the OKLCH perceptual color space.
+//! Lightness changes in OKLCH keep the perceived hue and chroma of the color,
+//! unlike changes in HSL.
+
+
+
+use super::{ Color, Notation, notation::byte };
+
+
+
+impl Color {
+    /// Returns the OKLCH lightness in `[0, 1]`, chroma and hue in degrees of the color.
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let linear = |c: u8| {
+            let c = c as f32 / 255.0;
+
+            match c <= 0.04045 {
+                true => c / 12.92,
+                false => ((c + 0.055) / 1.055).powf(2.4),
+            }
+        };
+
+        let (r, g, b) = (linear(self.0), linear(self.1), linear(self.2));
+
+        let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+        let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+
+        let lightness = 0.21045426 * l + 0.7936178 * m - 0.004072047 * s;
+        let a = 1.9779985 * l - 2.4285922 * m + 0.4505937 * s;
+        let b = 0.025904037 * l + 0.78277177 * m - 0.80867577 * s;
+
+        (lightness, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0))
+    }
+
+    /// Creates a color from its OKLCH lightness, chroma and hue in degrees.
+    /// Channels outside of the sRGB gamut are clipped.
+    pub fn from_oklch(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Self {
+        let (a, b) = (chroma * hue.to_radians().cos(), chroma * hue.to_radians().sin());
+
+        let l = (lightness + 0.39633778 * a + 0.21580376 * b).powi(3);
+        let m = (lightness - 0.105561346 * a - 0.06385417 * b).powi(3);
+        let s = (lightness - 0.08948418 * a - 1.2914855 * b).powi(3);
+
+        let gamma = |c: f32| match c <= 0.0031308 {
+            true => byte(c * 12.92),
+            false => byte(1.055 * c.powf(1.0 / 2.4) - 0.055),
+        };
+
+        Color(
+            gamma( 4.0767417 * l - 3.3077116 * m + 0.23096994 * s),
+            gamma(-1.268438 * l + 2.6097574 * m - 0.34131938 * s),
+            gamma(-0.0041960864 * l - 0.7034186 * m + 1.7076147 * s),
+            alpha.clamp(0.0, 1.0),
+            Notation::Tuple,
+        )
+    }
+}
@@ -0,0 +1,160 @@
+//! `serde` compatible version of a color entry.
+//! An entry is either a color in any `Notation`, or a `Derived` expression
+//! written as a string (e.g. `"lighten(accent, 0.1)"`).
+//! In place of a key, a color written as a string is wrapped (e.g. `Color("#ff8000")`).
+
+
+
+use super::{ Derived, Notation };
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{ self, IgnoredAny, MapAccess, SeqAccess, Visitor, value::MapAccessDeserializer },
+    ser::SerializeTuple,
+};
+
+use serde_derive::{ Deserialize, Serialize };
+
+use std::fmt;
+
+
+
+#[derive(Clone, Debug)]
+pub enum Color {
+    /// The color is defined in place.
+    Value( super::Color ),
+
+    /// The color is derived from other colors.
+    Derived( Derived ),
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self, serializer.is_human_readable()) {
+            (Color::Value(color), true) => color.serialize(serializer),
+            (Color::Derived(derived), true) => serializer.collect_str(derived),
+            (Color::Value(color), false) => Tagged::Value(*color).serialize(serializer),
+            (Color::Derived(derived), false) => Tagged::Derived(derived.to_string()).serialize(serializer),
+        }
+    }
+}
+
+impl crate::serial::Inline for Color {
+    fn serialize_inline<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let string = match self {
+            Color::Value(color) => matches!(color.notation(), Notation::Hex | Notation::Hsl | Notation::Name),
+            Color::Derived(_) => true,
+        };
+
+        // A string in place of a color is a key, so colors written as strings are wrapped.
+        match string && serializer.is_human_readable() {
+            true => {
+                let mut tuple = serializer.serialize_tuple(1)?;
+                tuple.serialize_element(self)?;
+                tuple.end()
+            },
+
+            false => self.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Binary formats are not self describing and use a tagged enum.
+        if !deserializer.is_human_readable() {
+            return match Tagged::deserialize(deserializer)? {
+                Tagged::Value(color) => Ok( Color::Value(color) ),
+                Tagged::Derived(string) => string.parse().map(Color::Derived).map_err(de::Error::custom),
+            };
+        }
+
+        deserializer.deserialize_any(EntryVisitor)
+    }
+}
+
+/// Color entry of non self describing formats.
+#[derive(Deserialize, Serialize)]
+enum Tagged {
+    Value( super::Color ),
+    Derived( String ),
+}
+
+/// Visitor of a color entry in self describing formats.
+struct EntryVisitor;
+
+impl<'de> Visitor<'de> for EntryVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a color or a derived color expression")
+    }
+
+    fn visit_str<E: de::Error>(self, string: &str) -> Result<Color, E> {
+        match Derived::is_expression(string) {
+            true => string.parse().map(Color::Derived).map_err(E::custom),
+            false => string.parse().map(Color::Value).map_err(E::custom),
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+        let r = match seq.next_element()? {
+            Some(First::Channel(r)) => r,
+
+            // A wrapped color or expression.
+            Some(First::String(string)) => match seq.next_element::<IgnoredAny>()? {
+                Some(_) => return Err( de::Error::invalid_length(2, &self) ),
+                _ => return self.visit_str(&string),
+            },
+
+            _ => return Err( de::Error::invalid_length(0, &self) ),
+        };
+
+        let g = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let b = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let a = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(3, &self))?;
+
+        Ok( Color::Value( super::Color(r, g, b, a, Notation::Tuple) ) )
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Color, A::Error> {
+        super::Color::deserialize( MapAccessDeserializer::new(map) ).map(Color::Value)
+    }
+}
+
+
+/// First element of a color sequence, which is either the red channel of a
+/// color tuple or a wrapped color or expression.
+enum First {
+    Channel( u8 ),
+    String( String ),
+}
+
+impl<'de> Deserialize<'de> for First {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(FirstVisitor)
+    }
+}
+
+/// Visitor of the first element of a color sequence.
+struct FirstVisitor;
+
+impl<'de> Visitor<'de> for FirstVisitor {
+    type Value = First;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a color channel or a color string")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<First, E> {
+        u8::try_from(value).map(First::Channel).map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<First, E> {
+        u8::try_from(value).map(First::Channel).map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, string: &str) -> Result<First, E> {
+        Ok( First::String(string.into()) )
+    }
+}
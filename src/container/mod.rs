@@ -6,7 +6,7 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Border, Color, Theme };
+use crate::{ Background, Border, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
 
 use iced::{
     widget::{
@@ -23,7 +23,7 @@ use std::sync::Arc;
 #[derive(Clone, Debug)]
 pub struct Container {
     /// Background of the container.
-    pub color: Arc<Color>,
+    pub color: Background,
 
     /// Border of the container.
     pub border: Arc<Border>,
@@ -31,26 +31,35 @@ pub struct Container {
 
 impl Container {
     /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::Container, theme: &Theme) -> Result<Self, ()> {
+    pub(crate) fn create(serial: &serial::Container, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
         // Get the color of the container.
-        let color = match theme.color.get(&serial.color) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let color = theme.getbackground(&serial.color, "color", &mut errors);
 
         // Get the border of the container.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
+        let border = theme.getborder(&serial.border, "border", &mut errors);
+
+        match (color, border) {
+            (Some(color), Some(border)) => Ok( Container { color, border } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Container {
+        serial::Container { color: export.background(&self.color), border: export.border(&self.border) }
+    }
 
-        Ok( Container { color, border } )
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Container { color: remap.background(&self.color), border: remap.border(&self.border) }
     }
 }
 
-impl Into<iced::theme::Container> for Container {
-    fn into(self) -> iced::theme::Container {
-        iced::theme::Container::Custom( Box::new(self) )
+impl From<Container> for iced::theme::Container {
+    fn from(container: Container) -> Self {
+        iced::theme::Container::Custom( Box::new(container) )
     }
 }
 
@@ -60,7 +69,7 @@ impl StyleSheet for Container {
     fn appearance(&self, _: &Self::Style) -> Appearance {
         Appearance {
             text_color: None,
-            background: Some( (*self.color).into() ),
+            background: Some( (&self.color).into() ),
             border_radius: self.border.radius,
             border_width: self.border.width,
             border_color: (*self.border.color).into(),
@@ -2,15 +2,17 @@
 
 
 
+use crate::serial::{ BackgroundRef, BorderRef };
+
 use serde_derive::{ Deserialize, Serialize };
 
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Container {
-    /// Key to the background color.
-    pub color: String,
+    /// Reference to the background color or gradient.
+    pub color: BackgroundRef,
 
-    /// Key to the border definition.
-    pub border: String,
+    /// Reference to the border definition.
+    pub border: BorderRef,
 }
@@ -0,0 +1,228 @@
+//! Errors produced while parsing a serialized theme or loading a theme package.
+
+
+
+use std::{
+    fmt, io,
+    path::PathBuf,
+};
+
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// Section of the theme in which the error was found (e.g. `button`).
+    pub section: &'static str,
+
+    /// Key of the entry that failed to parse.
+    /// Empty for sections without entries (e.g. `meta`).
+    pub entry: String,
+
+    /// Path to the failing field inside the entry (e.g. `hovered.border`).
+    /// Empty if the error concerns the entry as a whole.
+    pub field: String,
+
+    /// Cause of the error.
+    pub kind: ErrorKind,
+}
+
+impl ParseError {
+    /// Creates an error for the given field.
+    /// The section and entry are filled in by the caller that knows them.
+    pub(crate) fn new(field: &str, kind: ErrorKind) -> Self {
+        ParseError { section: "", entry: String::new(), field: field.into(), kind }
+    }
+
+    /// Sets the section and entry of the error.
+    pub(crate) fn locate(mut self, section: &'static str, entry: &str) -> Self {
+        self.section = section;
+        self.entry = entry.into();
+        self
+    }
+
+    /// Prepends the given path to the field of the error.
+    pub(crate) fn prefix(mut self, path: &str) -> Self {
+        self.field = match self.field.is_empty() {
+            true => path.into(),
+            false => format!("{}.{}", path, self.field),
+        };
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.entry.is_empty(), self.field.is_empty()) {
+            (true, true) => write!(f, "{}: {}", self.section, self.kind),
+            (true, false) => write!(f, "{}.{}: {}", self.section, self.field, self.kind),
+            (_, true) => write!(f, "{} `{}`: {}", self.section, self.entry, self.kind),
+            _ => write!(f, "{} `{}`.{}: {}", self.section, self.entry, self.field, self.kind),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorKind {
+    /// The referenced color key does not exist.
+    MissingColor(String),
+
+    /// The referenced border key does not exist.
+    MissingBorder(String),
+
+    /// The image file does not exist in the package.
+    MissingImage(String),
+
+    /// The font file does not exist in the package.
+    MissingFont(String),
+
+    /// The gradient has an invalid number of stops or an invalid offset.
+    InvalidGradient(String),
+
+    /// The inherited entry does not exist.
+    UnresolvedInheritance(String),
+
+    /// The inherited entry exists but failed to parse.
+    FailedInheritance(String),
+
+    /// The inheritance chain loops back on itself.
+    /// Contains the path of the loop, starting and ending on the same field.
+    CyclicInheritance(Vec<String>),
+
+    /// The derived color depends on itself.
+    /// Contains the path of the loop, starting and ending on the same color.
+    CyclicColor(Vec<String>),
+
+    /// None of the states of the entry are defined or inherited.
+    NoDefinedState,
+
+    /// The version is not a valid semantic version. Contains the version and the reason.
+    InvalidVersion(String, String),
+
+    /// The license is not a valid SPDX expression. Contains the license and the reason.
+    InvalidLicense(String, String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::MissingColor(key) => write!(f, "color `{}` not found", key),
+            ErrorKind::MissingBorder(key) => write!(f, "border `{}` not found", key),
+            ErrorKind::MissingImage(path) => write!(f, "image `{}` not found", path),
+            ErrorKind::MissingFont(path) => write!(f, "font `{}` not found", path),
+            ErrorKind::InvalidGradient(reason) => write!(f, "invalid gradient: {}", reason),
+            ErrorKind::UnresolvedInheritance(key) => write!(f, "inherited entry `{}` not found", key),
+            ErrorKind::FailedInheritance(key) => write!(f, "inherited entry `{}` failed to parse", key),
+            ErrorKind::CyclicInheritance(path) => write!(f, "cyclic inheritance {}", path.join(" -> ")),
+            ErrorKind::CyclicColor(path) => write!(f, "cyclic color derivation {}", path.join(" -> ")),
+            ErrorKind::NoDefinedState => f.write_str("no state is defined"),
+            ErrorKind::InvalidVersion(version, reason) => write!(f, "invalid version `{}`: {}", version, reason),
+            ErrorKind::InvalidLicense(license, reason) => write!(f, "invalid SPDX license `{}`: {}", license, reason),
+        }
+    }
+}
+
+
+
+/// Error produced while loading a theme package.
+#[derive(Debug)]
+pub struct PackageError {
+    /// Path of the failing file, relative to the root of the package.
+    /// Empty if the error concerns a whole archive or package folder.
+    pub file: PathBuf,
+
+    /// Cause of the error.
+    pub kind: PackageErrorKind,
+}
+
+impl PackageError {
+    /// Creates an error for the given file of the package.
+    pub(crate) fn new(file: impl Into<PathBuf>, kind: PackageErrorKind) -> Self {
+        PackageError { file: file.into(), kind }
+    }
+}
+
+impl fmt::Display for PackageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.file.as_os_str().is_empty() {
+            true => write!(f, "{}", self.kind),
+            false => write!(f, "`{}`: {}", self.file.display(), self.kind),
+        }
+    }
+}
+
+impl std::error::Error for PackageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            PackageErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+
+
+#[derive(Debug)]
+pub enum PackageErrorKind {
+    /// The file could not be read.
+    Io(io::Error),
+
+    /// The package has no file with this name in any of the extensions of the format.
+    MissingFile,
+
+    /// The file could not be deserialized by the format.
+    Format(String),
+
+    /// The theme could not be parsed.
+    Parse(Vec<ParseError>),
+
+    /// The metadata is not valid.
+    Meta(Vec<ParseError>),
+
+    /// The font file could not be read.
+    Font(String),
+
+    /// The archive could not be read.
+    Archive(String),
+
+    /// The archive entry is absolute or escapes the package with `..`.
+    UnsafePath,
+
+    /// The archive entry is a link or a special file.
+    UnsupportedEntry,
+
+    /// The archive exceeds one of the limits of the loader.
+    Limit(String),
+}
+
+impl fmt::Display for PackageErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PackageErrorKind::Io(e) => write!(f, "{}", e),
+            PackageErrorKind::MissingFile => f.write_str("file not found"),
+            PackageErrorKind::Format(e) => write!(f, "invalid format: {}", e),
+            PackageErrorKind::Font(e) => write!(f, "invalid font: {}", e),
+            PackageErrorKind::Archive(e) => write!(f, "invalid archive: {}", e),
+            PackageErrorKind::UnsafePath => f.write_str("path is absolute or escapes the package"),
+            PackageErrorKind::UnsupportedEntry => f.write_str("links and special files are not allowed"),
+            PackageErrorKind::Limit(limit) => write!(f, "exceeds the limit of {}", limit),
+            PackageErrorKind::Parse(errors) => list(f, "invalid theme", errors),
+            PackageErrorKind::Meta(errors) => list(f, "invalid metadata", errors),
+        }
+    }
+}
+
+/// Writes a list of parse errors, one per line, after a title.
+fn list(f: &mut fmt::Formatter, title: &str, errors: &[ParseError]) -> fmt::Result {
+    f.write_str(title)?;
+
+    for error in errors {
+        write!(f, "\n  {}", error)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//! Fonts of a theme.
+//! The fonts are shipped in the `font/` folder of a theme package and named in
+//! the `font` section of the theme, so they can be used by name in the widgets.
+
+
+
+use crate::Theme;
+
+use iced::{
+    Command,
+
+    font::{ self, Family, Stretch, Weight, },
+};
+
+use std::{
+    collections::HashMap,
+    sync::{ Arc, Mutex, OnceLock },
+};
+
+
+
+#[derive(Clone, Debug)]
+pub struct Font {
+    /// Descriptor to select the font in `iced` widgets.
+    pub descriptor: iced::Font,
+
+    /// Contents of the font file.
+    pub bytes: Vec<u8>,
+}
+
+impl Font {
+    /// Creates a font from the contents of a TrueType or OpenType file.
+    /// The descriptor is read from the family name, weight, width and spacing of the font.
+    /// The family name is interned, as `iced` selects the fonts by `&'static str` names.
+    pub fn from_memory(bytes: Vec<u8>) -> Result<Self, ttf_parser::FaceParsingError> {
+        let face = ttf_parser::Face::parse(&bytes, 0)?;
+
+        let family = match family(&face) {
+            Some(name) => Family::Name( intern(name) ),
+            _ => Family::SansSerif,
+        };
+
+        let weight = match face.weight().to_number() {
+            0..=149 => Weight::Thin,
+            150..=249 => Weight::ExtraLight,
+            250..=349 => Weight::Light,
+            350..=449 => Weight::Normal,
+            450..=549 => Weight::Medium,
+            550..=649 => Weight::Semibold,
+            650..=749 => Weight::Bold,
+            750..=849 => Weight::ExtraBold,
+            _ => Weight::Black,
+        };
+
+        let stretch = match face.width() {
+            ttf_parser::Width::UltraCondensed => Stretch::UltraCondensed,
+            ttf_parser::Width::ExtraCondensed => Stretch::ExtraCondensed,
+            ttf_parser::Width::Condensed => Stretch::Condensed,
+            ttf_parser::Width::SemiCondensed => Stretch::SemiCondensed,
+            ttf_parser::Width::Normal => Stretch::Normal,
+            ttf_parser::Width::SemiExpanded => Stretch::SemiExpanded,
+            ttf_parser::Width::Expanded => Stretch::Expanded,
+            ttf_parser::Width::ExtraExpanded => Stretch::ExtraExpanded,
+            ttf_parser::Width::UltraExpanded => Stretch::UltraExpanded,
+        };
+
+        let descriptor = iced::Font { family, weight, stretch, monospaced: face.is_monospaced() };
+
+        Ok( Font { descriptor, bytes } )
+    }
+}
+
+
+
+impl Theme {
+    /// Creates the command that loads the fonts of the theme into `iced`.
+    /// Run it at startup, before any widget uses the descriptors of the fonts.
+    pub fn load_fonts(&self) -> Command<Result<(), font::Error>> {
+        let mut fonts: Vec<&Arc<Font>> = Vec::new();
+
+        // A file named more than once is loaded once.
+        for font in self.font.values() {
+            if !fonts.iter().any(|f| Arc::ptr_eq(f, font)) {
+                fonts.push(font);
+            }
+        }
+
+        Command::batch( fonts.into_iter().map(|f| font::load( f.bytes.clone() )) )
+    }
+}
+
+
+
+/// Gets the family name of a font, as selected by `iced`.
+/// The typographic family is preferred over the legacy family, and English over other languages.
+fn family(face: &ttf_parser::Face) -> Option<String> {
+    let names: Vec<_> = face.names().into_iter().filter(|name| name.is_unicode()).collect();
+
+    [ttf_parser::name_id::TYPOGRAPHIC_FAMILY, ttf_parser::name_id::FAMILY].into_iter().find_map(|id| {
+        let mut names = names.iter().filter(|name| name.name_id == id);
+        let english = names.clone().find(|name| name.language() == ttf_parser::Language::English_UnitedStates);
+
+        english.or_else(|| names.next()).and_then(|name| name.to_string())
+    })
+}
+
+/// Returns a static version of a family name.
+/// Each name is leaked once, so loading the same fonts again does not leak more memory.
+fn intern(name: String) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashMap<String, &'static str>>> = OnceLock::new();
+
+    let mut names = NAMES.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+
+    match names.get(&name) {
+        Some(name) => name,
+        _ => {
+            let interned: &'static str = Box::leak( name.clone().into_boxed_str() );
+            names.insert(name, interned);
+            interned
+        },
+    }
+}
@@ -0,0 +1,98 @@
+//! Linear gradient theme.
+
+
+
+pub(crate) mod serial;
+
+
+
+use crate::{ Color, Theme, error::{ ErrorKind, ParseError }, theme::{ export::Export, remap::Remap }, };
+
+use iced::{
+    Degrees,
+
+    gradient::Linear,
+};
+
+use std::sync::Arc;
+
+
+
+#[derive(Clone, Debug)]
+pub struct Gradient {
+    /// Angle of the gradient, in degrees.
+    pub angle: f32,
+
+    /// Color stops of the gradient, as an offset and a color.
+    pub stops: Vec<(f32, Arc<Color>)>,
+}
+
+impl Gradient {
+    /// Maximum number of color stops supported by `iced`.
+    pub const MAX_STOPS: usize = 8;
+
+    /// Attempts to create a theme from its serialized version.
+    pub(crate) fn create(serial: &serial::Gradient, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        if serial.stops.is_empty() || serial.stops.len() > Self::MAX_STOPS {
+            let reason = format!("expected 1 to {} stops, found {}", Self::MAX_STOPS, serial.stops.len());
+            errors.push( ParseError::new("stops", ErrorKind::InvalidGradient(reason)) );
+        }
+
+        let mut stops = Vec::with_capacity(serial.stops.len());
+
+        for (i, (offset, color)) in serial.stops.iter().enumerate() {
+            let field = format!("stops.{}", i);
+
+            if !(0.0..=1.0).contains(offset) {
+                let reason = format!("offset {} is outside of [0, 1]", offset);
+                errors.push( ParseError::new(&field, ErrorKind::InvalidGradient(reason)) );
+            }
+
+            if let Some(color) = theme.getcolor(color, &field, &mut errors) {
+                stops.push( (*offset, color) );
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok( Gradient { angle: serial.angle, stops } ),
+            false => Err(errors),
+        }
+    }
+
+    /// Exports the gradient to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Gradient {
+        let stops = self.stops.iter().map(|(offset, color)| (*offset, export.color(color))).collect();
+
+        serial::Gradient { angle: self.angle, stops }
+    }
+
+    /// Remaps the colors of the gradient.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        let stops = self.stops.iter().map(|(offset, color)| (*offset, remap.color(color))).collect();
+
+        Gradient { angle: self.angle, stops }
+    }
+}
+
+impl From<&Gradient> for iced::Gradient {
+    fn from(gradient: &Gradient) -> Self {
+        let linear = gradient.stops.iter()
+            .fold(Linear::new( Degrees(gradient.angle) ), |linear, (offset, color)| linear.add_stop(*offset, (**color).into()));
+
+        iced::Gradient::Linear(linear)
+    }
+}
+
+impl core::fmt::Display for Gradient {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Gradient {:.1} deg", self.angle)?;
+
+        for (offset, color) in &self.stops {
+            write!(f, " | {:.3}: {}", offset, color)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+//! `serde` compatible version of the gradient theme.
+
+
+
+use crate::serial::ColorRef;
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Gradient {
+    /// Angle of the gradient, in degrees.
+    pub angle: f32,
+
+    /// Color stops of the gradient, as an offset in `[0, 1]` and a reference to the color.
+    pub stops: Vec<(f32, ColorRef)>,
+}
@@ -0,0 +1,71 @@
+//! Images and icons of a theme.
+
+
+
+pub mod serial;
+
+
+
+use iced::{
+    Size,
+
+    widget::{ image, svg, },
+};
+
+
+
+#[derive(Clone, Debug)]
+pub struct Image {
+    /// Handle to the loaded image.
+    pub handle: Handle,
+
+    /// Alternative text of the image, for accessibility.
+    pub alt: Option<String>,
+
+    /// Intended size of the image.
+    pub size: Option<Size>,
+}
+
+impl Image {
+    /// Creates an image from its handle and its serialized metadata.
+    pub(crate) fn new(handle: Handle, meta: Option<&serial::ImageMeta>) -> Self {
+        let meta = meta.cloned().unwrap_or_default();
+
+        Image { handle, alt: meta.alt, size: meta.size.map(|(w, h)| Size::new(w, h)) }
+    }
+}
+
+
+
+/// Handle to a loaded image, ready to be used in an `iced` widget.
+#[derive(Clone, Debug)]
+pub enum Handle {
+    /// Raster image (PNG, JPEG, ...), for the `image` widget.
+    Raster( image::Handle ),
+
+    /// Vector image, for the `svg` widget.
+    Vector( svg::Handle ),
+}
+
+impl Handle {
+    /// Extensions of the raster images.
+    pub const RASTER: [&'static str; 8] = ["png", "jpg", "jpeg", "gif", "bmp", "ico", "webp", "tiff"];
+
+    /// Extensions of the vector images.
+    pub const VECTOR: [&'static str; 1] = ["svg"];
+
+    /// Creates a handle from the contents of a file with the given extension.
+    /// Returns `None` if the extension is not of a known image format.
+    pub fn from_memory(extension: &str, bytes: Vec<u8>) -> Option<Self> {
+        let extension = extension.to_ascii_lowercase();
+
+        if Self::VECTOR.contains(&extension.as_str()) {
+            return Some( Handle::Vector( svg::Handle::from_memory(bytes) ) );
+        }
+
+        match Self::RASTER.contains(&extension.as_str()) {
+            true => Some( Handle::Raster( image::Handle::from_memory(bytes) ) ),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! `serde` compatible version of the image metadata.
+
+
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+
+/// Metadata of an image, from the `img/img.meta` file of a package.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ImageMeta {
+    /// Alternative text of the image, for accessibility.
+    #[serde(default)]
+    pub alt: Option<String>,
+
+    /// Intended width and height of the image.
+    #[serde(default)]
+    pub size: Option<(f32, f32)>,
+}
@@ -4,20 +4,37 @@
 
 
 
+pub mod accessibility;
+pub mod application;
+pub mod apptheme;
+pub mod background;
 pub mod border;
 pub mod button;
-//pub mod checkbox;
+pub mod checkbox;
 pub mod color;
 pub mod container;
+pub mod error;
+#[cfg(feature = "font")]
+pub mod font;
+pub mod gradient;
+#[cfg(feature = "image")]
+pub mod image;
+#[cfg(feature = "meta")]
+pub mod meta;
+pub mod package;
 pub mod panegrid;
 pub mod picklist;
 pub mod progressbar;
-//pub mod radio;
-//pub mod rule;
+pub mod radio;
+pub mod rule;
 pub mod scrollable;
-//pub mod slider;
+pub mod slider;
+pub mod svg;
+pub mod text;
 pub mod textinput;
+pub mod toggler;
 pub mod tooltip;
+pub mod vision;
 
 pub mod serial;
 
@@ -25,15 +42,34 @@ pub mod theme;
 
 
 
+pub use application::Application;
+pub use apptheme::{ AppTheme, Defaults, Style };
+pub use background::Background;
 pub use border::Border;
 pub use button::Button;
+pub use checkbox::Checkbox;
 pub use color::Color;
 pub use container::Container;
+pub use error::{ ErrorKind, PackageError, ParseError };
+#[cfg(feature = "font")]
+pub use font::Font;
+pub use gradient::Gradient;
+#[cfg(feature = "image")]
+pub use image::Image;
+#[cfg(feature = "meta")]
+pub use meta::ThemeMeta;
+pub use package::ThemePackage;
 pub use panegrid::PaneGrid;
 pub use picklist::Picklist;
 pub use progressbar::ProgressBar;
+pub use radio::Radio;
+pub use rule::Rule;
 pub use scrollable::Scrollable;
+pub use slider::Slider;
+pub use svg::Svg;
+pub use text::Text;
 pub use textinput::TextInput;
+pub use toggler::Toggler;
 pub use tooltip::Tooltip;
 
 pub use theme::Theme;
@@ -0,0 +1,94 @@
+//! Metadata of a theme, such as its authors, license and version.
+//! Usually loaded from the `theme.meta` file of a theme package.
+
+
+
+pub mod serial;
+
+
+
+pub use serial::Variant;
+
+
+
+use crate::{ Color, error::{ ErrorKind, ParseError }, };
+
+use semver::Version;
+
+
+
+#[derive(Clone, Debug)]
+pub struct ThemeMeta {
+    /// Authors of the theme.
+    pub authors: Vec<String>,
+
+    /// SPDX license expression of the theme.
+    pub license: Option<String>,
+
+    /// Version of the theme.
+    pub version: Version,
+
+    /// Homepage or repository of the theme.
+    pub homepage: Option<String>,
+
+    /// Tags to search and group themes.
+    pub tags: Vec<String>,
+
+    /// Whether the theme is light or dark.
+    pub variant: Variant,
+
+    /// Colors that represent the theme in previews.
+    pub preview: Vec<Color>,
+
+    /// Minimum version of `marcel` required by the theme.
+    pub marcel: Option<Version>,
+}
+
+impl ThemeMeta {
+    /// Validates the serialized metadata.
+    /// The versions must be valid semantic versions and the license a valid SPDX expression.
+    pub fn parse(serial: &serial::ThemeMeta) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        let mut version = |field: &str, string: &str| match Version::parse(string) {
+            Ok(version) => Some(version),
+            Err(e) => { errors.push( ParseError::new(field, ErrorKind::InvalidVersion(string.into(), e.to_string())).locate("meta", "") ); None },
+        };
+
+        let theme = version("version", &serial.version);
+
+        let marcel = match &serial.marcel {
+            Some(string) => version("marcel", string).map(Some),
+            _ => Some(None),
+        };
+
+        if let Some(license) = &serial.license {
+            if let Err(e) = spdx::Expression::parse(license) {
+                errors.push( ParseError::new("license", ErrorKind::InvalidLicense(license.clone(), e.reason.to_string())).locate("meta", "") );
+            }
+        }
+
+        match (theme, marcel) {
+            (Some(version), Some(marcel)) if errors.is_empty() => Ok( ThemeMeta {
+                authors: serial.authors.clone(),
+                license: serial.license.clone(),
+                version,
+                homepage: serial.homepage.clone(),
+                tags: serial.tags.clone(),
+                variant: serial.variant,
+                preview: serial.preview.clone(),
+                marcel,
+            }),
+
+            _ => Err(errors),
+        }
+    }
+
+    /// Checks if this version of `marcel` is recent enough for the theme.
+    pub fn is_supported(&self) -> bool {
+        match &self.marcel {
+            Some(minimum) => Version::parse( env!("CARGO_PKG_VERSION") ).is_ok_and(|current| current >= *minimum),
+            _ => true,
+        }
+    }
+}
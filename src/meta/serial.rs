@@ -0,0 +1,54 @@
+//! `serde` compatible version of the theme metadata.
+
+
+
+use crate::Color;
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThemeMeta {
+    /// Authors of the theme.
+    #[serde(default)]
+    pub authors: Vec<String>,
+
+    /// SPDX license expression of the theme (e.g. `MIT OR Apache-2.0`).
+    #[serde(default)]
+    pub license: Option<String>,
+
+    /// Semantic version of the theme.
+    pub version: String,
+
+    /// Homepage or repository of the theme.
+    #[serde(default)]
+    pub homepage: Option<String>,
+
+    /// Tags to search and group themes.
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Whether the theme is light or dark.
+    pub variant: Variant,
+
+    /// Colors that represent the theme in previews.
+    #[serde(default)]
+    pub preview: Vec<Color>,
+
+    /// Minimum semantic version of `marcel` required by the theme.
+    #[serde(default)]
+    pub marcel: Option<String>,
+}
+
+
+
+/// Light or dark classification of a theme.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum Variant {
+    /// Dark text on a light background.
+    Light,
+
+    /// Light text on a dark background.
+    Dark,
+}
@@ -0,0 +1,206 @@
+//! Theme packages stored in `.zip` and `.tar.gz` archives.
+//! Archives are read in memory and never extracted. Every entry is validated
+//! before it is read: absolute paths, `..` components, links and special files
+//! are rejected, and the number and size of the entries are limited.
+
+
+
+use super::{ Format, ThemePackage, };
+
+use crate::error::{ PackageError, PackageErrorKind };
+
+use flate2::read::GzDecoder;
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{ BufReader, Read, Seek },
+    path::{ Path, PathBuf },
+};
+
+
+
+/// Limits of the contents of an archive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum number of entries, including folders.
+    pub entries: usize,
+
+    /// Maximum uncompressed size of a file, in bytes.
+    pub file: u64,
+
+    /// Maximum uncompressed size of all the files, in bytes.
+    pub total: u64,
+}
+
+impl Default for Limits {
+    /// 1024 entries, 16 MiB per file and 64 MiB in total.
+    fn default() -> Self {
+        Limits { entries: 1024, file: 16 << 20, total: 64 << 20 }
+    }
+}
+
+
+
+impl ThemePackage {
+    /// Opens the package in the given `.zip`, `.tar.gz` or `.tgz` archive, reading its files in the format `F`.
+    /// The `theme` file must be at the root of the archive.
+    pub fn open_archive<F: Format>(path: impl AsRef<Path>, limits: &Limits) -> Result<Self, PackageError> {
+        let path = path.as_ref();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("").to_ascii_lowercase();
+
+        let file = File::open(path).map_err(|e| PackageError::new( path, PackageErrorKind::Io(e) ))?;
+        let reader = BufReader::new(file);
+
+        let package = if name.ends_with(".zip") {
+            Self::from_zip::<F>(reader, limits)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Self::from_tar_gz::<F>(reader, limits)
+        } else {
+            Err( invalid("unknown archive extension") )
+        };
+
+        // Errors of the whole archive are reported on the archive file.
+        package.map_err(|e| match e.file.as_os_str().is_empty() {
+            true => PackageError::new( path, e.kind ),
+            false => e,
+        })
+    }
+
+    /// Loads a package from a `.zip` archive.
+    pub fn from_zip<F: Format>(reader: impl Read + Seek, limits: &Limits) -> Result<Self, PackageError> {
+        let mut archive = zip::ZipArchive::new(reader).map_err(invalid)?;
+
+        if archive.len() > limits.entries {
+            return Err( exceeded( format!("{} entries", limits.entries) ) );
+        }
+
+        let mut reader = Reader::new(limits);
+
+        for i in 0..archive.len() {
+            let entry = archive.by_index(i).map_err(invalid)?;
+            let name = entry.name().to_string();
+
+            // Symbolic links are stored as files with the link mode.
+            if entry.unix_mode().is_some_and(|mode| mode & 0o170000 == 0o120000) {
+                return Err( PackageError::new( name, PackageErrorKind::UnsupportedEntry ) );
+            }
+
+            match entry.is_dir() {
+                true => { sanitize(&name)?; },
+                false => reader.read(&name, entry)?,
+            }
+        }
+
+        Self::load::<F>( reader.files )
+    }
+
+    /// Loads a package from a `.tar.gz` archive.
+    pub fn from_tar_gz<F: Format>(reader: impl Read, limits: &Limits) -> Result<Self, PackageError> {
+        let mut archive = tar::Archive::new( GzDecoder::new(reader) );
+        let mut reader = Reader::new(limits);
+        let mut count = 0;
+
+        for entry in archive.entries().map_err(invalid)? {
+            let entry = entry.map_err(invalid)?;
+            let name = String::from_utf8_lossy( &entry.path_bytes() ).into_owned();
+
+            count += 1;
+
+            if count > limits.entries {
+                return Err( exceeded( format!("{} entries", limits.entries) ) );
+            }
+
+            match entry.header().entry_type() {
+                tar::EntryType::Regular | tar::EntryType::Continuous => reader.read(&name, entry)?,
+                tar::EntryType::Directory => { sanitize(&name)?; },
+                tar::EntryType::XGlobalHeader => (),
+                _ => return Err( PackageError::new( name, PackageErrorKind::UnsupportedEntry ) ),
+            }
+        }
+
+        Self::load::<F>( reader.files )
+    }
+}
+
+
+
+/// Reader of the files of an archive that enforces the size limits.
+struct Reader<'a> {
+    /// Limits of the archive.
+    limits: &'a Limits,
+
+    /// Files read so far, by sanitized path.
+    files: BTreeMap<PathBuf, Vec<u8>>,
+
+    /// Uncompressed size of the files read so far.
+    total: u64,
+}
+
+impl<'a> Reader<'a> {
+    fn new(limits: &'a Limits) -> Self {
+        Reader { limits, files: BTreeMap::new(), total: 0 }
+    }
+
+    /// Reads a file of the archive.
+    /// The sizes are counted while reading, as the sizes in the headers can not be trusted.
+    fn read(&mut self, name: &str, entry: impl Read) -> Result<(), PackageError> {
+        let path = sanitize(name)?;
+        let limit = self.limits.file.min(self.limits.total - self.total);
+
+        let mut bytes = Vec::new();
+        entry.take(limit + 1).read_to_end(&mut bytes).map_err(|e| PackageError::new( &path, PackageErrorKind::Io(e) ))?;
+
+        if bytes.len() as u64 > limit {
+            let limit = match limit == self.limits.file {
+                true => format!("{} bytes per file", self.limits.file),
+                false => format!("{} bytes in total", self.limits.total),
+            };
+
+            return Err( PackageError::new( &path, PackageErrorKind::Limit(limit) ) );
+        }
+
+        self.total += bytes.len() as u64;
+        self.files.insert(path, bytes);
+
+        Ok(())
+    }
+}
+
+
+
+/// Validates the path of an archive entry, returning it as a relative path.
+/// Both `/` and `\` are separators, and absolute paths, drive prefixes and `..` are rejected.
+fn sanitize(name: &str) -> Result<PathBuf, PackageError> {
+    let unsafe_path = || PackageError::new( name, PackageErrorKind::UnsafePath );
+
+    if name.starts_with(['/', '\\']) {
+        return Err( unsafe_path() );
+    }
+
+    let mut path = PathBuf::new();
+
+    for part in name.split(['/', '\\']) {
+        match part {
+            "" | "." => continue,
+            ".." => return Err( unsafe_path() ),
+            part if part.contains(':') || part.contains('\0') => return Err( unsafe_path() ),
+            part => path.push(part),
+        }
+    }
+
+    match path.as_os_str().is_empty() {
+        true => Err( unsafe_path() ),
+        false => Ok(path),
+    }
+}
+
+/// Creates an error of an archive that can not be read.
+fn invalid(e: impl std::fmt::Display) -> PackageError {
+    PackageError::new( PathBuf::new(), PackageErrorKind::Archive( e.to_string() ) )
+}
+
+/// Creates an error of an archive with too many entries.
+fn exceeded(limit: String) -> PackageError {
+    PackageError::new( PathBuf::new(), PackageErrorKind::Limit(limit) )
+}
@@ -0,0 +1,39 @@
+//! Serialization formats of the files of a theme package.
+
+
+
+use serde::de::DeserializeOwned;
+
+use std::fmt::Display;
+
+
+
+/// Format of the `theme` and `.meta` files of a package.
+/// Implement it to load packages with any `serde` format.
+pub trait Format {
+    /// Extensions of the files in this format, without the dot (e.g. `ron`).
+    const EXTENSIONS: &'static [&'static str];
+
+    /// Error returned when a file can not be deserialized.
+    type Error: Display;
+
+    /// Deserializes a value from the contents of a file.
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error>;
+}
+
+
+
+/// `ron` format, with the `.ron` extension.
+#[cfg(feature = "ron")]
+pub struct Ron;
+
+#[cfg(feature = "ron")]
+impl Format for Ron {
+    const EXTENSIONS: &'static [&'static str] = &["ron"];
+
+    type Error = ron::error::SpannedError;
+
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error> {
+        ron::de::from_bytes(bytes)
+    }
+}
@@ -0,0 +1,253 @@
+//! Theme packages.
+//! A package is a folder with the `theme` file, and optionally the `theme.meta`
+//! file and the `img/` and `font/` folders with the assets of the theme.
+
+
+
+pub mod format;
+
+#[cfg(feature = "archive")]
+pub mod archive;
+
+
+
+pub use format::Format;
+
+
+
+use crate::{
+    Theme,
+    error::{ PackageError, PackageErrorKind, ParseError },
+    theme::serial::Theme as Serial,
+};
+
+#[cfg(feature = "font")]
+use crate::Font;
+
+#[cfg(feature = "meta")]
+use crate::ThemeMeta;
+
+#[cfg(feature = "image")]
+use crate::{
+    Image,
+    image::{ Handle, serial::ImageMeta },
+};
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{ Path, PathBuf },
+};
+
+#[cfg(any(feature = "image", feature = "font"))]
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+
+
+/// Extensions of the font files.
+#[cfg(feature = "font")]
+const FONTS: [&str; 2] = ["ttf", "otf"];
+
+
+
+#[derive(Clone, Debug)]
+pub struct ThemePackage {
+    /// Theme of the package, parsed from the `theme` file.
+    pub theme: Theme,
+
+    /// Metadata of the `theme.meta` file, if any.
+    #[cfg(feature = "meta")]
+    pub meta: Option<ThemeMeta>,
+
+    /// Images of the `img/` folder, by path relative to the folder (e.g. `icons/save.svg`).
+    /// Their metadata is read from the `img/img.meta` file, by the same path.
+    #[cfg(feature = "image")]
+    pub images: HashMap<String, Arc<Image>>,
+
+    /// Fonts of the `font/` folder, by path relative to the folder (e.g. `Inter-Bold.ttf`).
+    #[cfg(feature = "font")]
+    pub fonts: HashMap<String, Arc<Font>>,
+}
+
+impl ThemePackage {
+    /// Opens the package in the given folder, reading its files in the format `F`.
+    /// Symbolic links inside the package are ignored.
+    pub fn open<F: Format>(path: impl AsRef<Path>) -> Result<Self, PackageError> {
+        let mut files = BTreeMap::new();
+        read::<F>(path.as_ref(), Path::new(""), &mut files)?;
+
+        Self::load::<F>(files)
+    }
+
+    /// Loads a package from its files, by path relative to the root of the package.
+    pub(crate) fn load<F: Format>(files: BTreeMap<PathBuf, Vec<u8>>) -> Result<Self, PackageError> {
+        // Finds a file with any of the extensions of the format.
+        let find = |files: &BTreeMap<PathBuf, Vec<u8>>, name: &str| F::EXTENSIONS.iter()
+            .map(|extension| PathBuf::from( format!("{}.{}", name, extension) ))
+            .find(|path| files.contains_key(path));
+
+        // Parse the theme.
+        let file = find(&files, "theme").ok_or_else(|| PackageError::new( pattern::<F>("theme"), PackageErrorKind::MissingFile ))?;
+
+        let serial: Serial = F::deserialize(&files[&file])
+            .map_err(|e| PackageError::new( &file, PackageErrorKind::Format( e.to_string() ) ))?;
+
+        let mut theme = Theme::new();
+        theme.parse(&serial).map_err(|e| PackageError::new( &file, PackageErrorKind::Parse(e) ))?;
+
+        let theme_file = file;
+
+        // Parse the metadata.
+        #[cfg(feature = "meta")]
+        let meta = match find(&files, "theme.meta") {
+            Some(file) => {
+                let serial = F::deserialize(&files[&file])
+                    .map_err(|e| PackageError::new( &file, PackageErrorKind::Format( e.to_string() ) ))?;
+
+                Some( ThemeMeta::parse(&serial).map_err(|e| PackageError::new( &file, PackageErrorKind::Meta(e) ))? )
+            },
+
+            _ => None,
+        };
+
+        // Parse the metadata of the images.
+        #[cfg(feature = "image")]
+        let imagemeta: HashMap<String, ImageMeta> = match find(&files, "img/img.meta") {
+            Some(file) => F::deserialize(&files[&file])
+                .map_err(|e| PackageError::new( &file, PackageErrorKind::Format( e.to_string() ) ))?,
+
+            _ => HashMap::new(),
+        };
+
+        // Get the assets. Files of unknown types are ignored.
+        #[cfg(feature = "image")]
+        let mut images = HashMap::new();
+
+        #[cfg(feature = "font")]
+        let mut fonts = HashMap::new();
+
+        #[cfg(any(feature = "image", feature = "font"))]
+        for (path, bytes) in files {
+            let extension = extension(&path);
+
+            #[cfg(feature = "image")]
+            if let Some(key) = key(&path, "img") {
+                if let Some(handle) = Handle::from_memory(&extension, bytes) {
+                    let image = Image::new( handle, imagemeta.get(&key) );
+                    images.insert( key, Arc::new(image) );
+                }
+
+                continue;
+            }
+
+            #[cfg(feature = "font")]
+            if let Some(key) = key(&path, "font") {
+                if FONTS.contains(&extension.as_str()) {
+                    let font = Font::from_memory(bytes).map_err(|e| PackageError::new( &path, PackageErrorKind::Font( e.to_string() ) ))?;
+                    fonts.insert( key, Arc::new(font) );
+                }
+            }
+        }
+
+        // Link the images and fonts of the theme.
+        let errors: &[Vec<ParseError>] = &[
+            #[cfg(feature = "image")]
+            theme.link_images(&images),
+
+            #[cfg(feature = "font")]
+            theme.link_fonts(&fonts),
+        ];
+
+        let errors = errors.concat();
+
+        if !errors.is_empty() {
+            return Err( PackageError::new( &theme_file, PackageErrorKind::Parse(errors) ) );
+        }
+
+        Ok( ThemePackage {
+            theme,
+
+            #[cfg(feature = "meta")]
+            meta,
+
+            #[cfg(feature = "image")]
+            images,
+
+            #[cfg(feature = "font")]
+            fonts,
+        } )
+    }
+}
+
+
+
+/// Reads the files of a folder of the package recursively.
+/// Only the files the package uses are read, and the paths of the errors are relative to the root.
+fn read<F: Format>(root: &Path, folder: &Path, files: &mut BTreeMap<PathBuf, Vec<u8>>) -> Result<(), PackageError> {
+    let io = |path: PathBuf| move |e| PackageError::new( path, PackageErrorKind::Io(e) );
+
+    let entries = fs::read_dir( root.join(folder) ).map_err( io(folder.to_path_buf()) )?;
+
+    for entry in entries {
+        let entry = entry.map_err( io(folder.to_path_buf()) )?;
+        let path = folder.join( entry.file_name() );
+        let kind = entry.file_type().map_err( io(path.clone()) )?;
+
+        if kind.is_dir() && ((cfg!(feature = "image") && path.starts_with("img")) || (cfg!(feature = "font") && path.starts_with("font"))) {
+            read::<F>(root, &path, files)?;
+        } else if kind.is_file() && used::<F>(&path) {
+            let bytes = fs::read( root.join(&path) ).map_err( io(path.clone()) )?;
+            files.insert(path, bytes);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if a file is used by the package, from its path relative to the root.
+fn used<F: Format>(path: &Path) -> bool {
+    #[cfg(feature = "image")]
+    if path.starts_with("img") {
+        let extension = extension(path);
+
+        return Handle::RASTER.contains(&extension.as_str()) || Handle::VECTOR.contains(&extension.as_str())
+            || F::EXTENSIONS.iter().any(|extension| path == Path::new( &format!("img/img.meta.{}", extension) ));
+    }
+
+    #[cfg(feature = "font")]
+    if path.starts_with("font") {
+        return FONTS.contains(&extension(path).as_str());
+    }
+
+    F::EXTENSIONS.iter().any(|extension| path == Path::new( &format!("theme.{}", extension) ) || (cfg!(feature = "meta") && path == Path::new( &format!("theme.meta.{}", extension) )))
+}
+
+/// Returns the extension of a file in lowercase, or an empty string if it has none.
+#[cfg(any(feature = "image", feature = "font"))]
+fn extension(path: &Path) -> String {
+    path.extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase()
+}
+
+/// Returns the path of a file inside the given folder of the package, with `/` separators.
+#[cfg(any(feature = "image", feature = "font"))]
+fn key(path: &Path, folder: &str) -> Option<String> {
+    let relative = path.strip_prefix(folder).ok()?;
+
+    let parts: Vec<&str> = relative.iter().map(|part| part.to_str()).collect::<Option<_>>()?;
+
+    match parts.is_empty() {
+        true => None,
+        false => Some( parts.join("/") ),
+    }
+}
+
+/// Returns the name of a file with any of the extensions of the format, for error messages.
+fn pattern<F: Format>(name: &str) -> String {
+    match F::EXTENSIONS {
+        [extension] => format!("{}.{}", name, extension),
+        extensions => format!("{}.{{{}}}", name, extensions.join(",")),
+    }
+}
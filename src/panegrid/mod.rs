@@ -6,13 +6,16 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Border, Color, Theme, };
+use crate::{ Background, Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
 
 use iced::widget::pane_grid::{ Appearance, Line, StyleSheet, };
 
 use serial::{ HoveredComponent, LineComponent, };
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
 
 
 
@@ -26,59 +29,47 @@ pub struct PaneGrid {
 }
 
 impl PaneGrid {
-    /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::PaneGrid, theme: &Theme) -> Result<Self, ()> {
-        // Get all the themes.
-        let picked  = Self::state( &serial.picked , theme, 0 )?;
-        let hovered = Self::state( &serial.hovered, theme, 1 )?;
-        let region = Self::hovered(&serial.region, theme)?;
-        
-        // Find the first state theme that is not None.
-        let default = match (&picked, &hovered) {
-            (Some(d), _) => d.clone(),
-            (_, Some(d)) => d.clone(),
-
-            _ => return Err(()),
-        };
-
-        // Hovered region must be defined.
-        let region = match region {
-            None => return Err(()),
-            Some(r) => r,
-        };
-
-        Ok(PaneGrid {
-            region,
-            state: [
-                picked.unwrap_or(default.clone()),
-                hovered.unwrap_or(default.clone()),
-            ],
-        })
-    }
+    /// Name of the pane grid hovered region.
+    pub(crate) const REGION: [&'static str; 1] = [ "region", ];
 
-    fn hovered(serial: &serial::HoveredComponent, theme: &Theme) -> Result<Option<Arc<Hovered>>, ()> {
-        match serial {
-            HoveredComponent::Defined( hovered ) => Ok( Some( Arc::new( Hovered::from(&hovered, &theme)? ) ) ),
+    /// Names of the pane grid line states, in order.
+    pub(crate) const STATES: [&'static str; 2] = [ "picked", "hovered", ];
 
-            HoveredComponent::Inherited( name ) => match theme.panegrid.get( name ) {
-                Some( panegrid ) => Ok( Some( panegrid.region.clone() ) ),
-                _ => Err(()),
-            },
+    /// Collects the regions and line states of the serialized pane grids for inheritance resolution.
+    pub(crate) fn groups(serial: &HashMap<String, serial::PaneGrid>) -> (Group<'_, HoveredComponent, Hovered>, Group<'_, LineComponent, State>) {
+        let mut regions = Group::new( "panegrid", &Self::REGION, Hovered::from );
+        let mut states = Group::new( "panegrid", &Self::STATES, State::from );
 
-            HoveredComponent::None => Ok( None ),
+        for (name, panegrid) in serial {
+            regions.insert( name, vec![ &panegrid.region ] );
+            states.insert( name, vec![ &panegrid.picked, &panegrid.hovered ] );
         }
+
+        (regions, states)
     }
 
-    fn state(serial: &serial::LineComponent, theme: &Theme, index: usize) -> Result<Option<Arc<State>>, ()> {
-        match serial {
-            LineComponent::Defined( state ) => Ok( Some( Arc::new( State::from(&state, &theme)? ) ) ),
+    /// Creates a theme from its resolved region and line states.
+    pub(crate) fn create(name: &str, regions: &Group<HoveredComponent, Hovered>, states: &Group<LineComponent, State>) -> Option<Self> {
+        let region = regions.get(name)?.pop()?;
+        let state = states.get(name)?;
+
+        Some( PaneGrid { region, state: state.try_into().ok()? } )
+    }
 
-            LineComponent::Inherited( name ) => match theme.panegrid.get( name ) {
-                Some( panegrid ) => Ok( Some( panegrid.state[index].clone() ) ),
-                _ => Err(()),
-            },
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::PaneGrid {
+        serial::PaneGrid {
+            region: HoveredComponent::Defined( self.region.serial(export) ),
+            picked: LineComponent::Defined( self.state[0].serial(export) ),
+            hovered: LineComponent::Defined( self.state[1].serial(export) ),
+        }
+    }
 
-            LineComponent::None => Ok( None ),
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        PaneGrid {
+            region: remap.shared(&self.region, Hovered::remap),
+            state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)),
         }
     }
 }
@@ -88,7 +79,7 @@ impl StyleSheet for PaneGrid {
 
     fn hovered_region(&self, _: &Self::Style) -> Appearance {
         Appearance {
-            background: (*self.region.background).into(),
+            background: (&self.region.background).into(),
             border_width: self.region.border.width,
             border_radius: self.region.border.radius,
             border_color: (*self.region.border.color).into(),
@@ -98,14 +89,14 @@ impl StyleSheet for PaneGrid {
     fn picked_split(&self, _: &Self::Style) -> Option<Line> {
         Some( Line {
             color: (*self.state[0].color).into(),
-            width: self.state[0].width.into(),
+            width: self.state[0].width,
         } )
     }
 
     fn hovered_split(&self, _: &Self::Style) -> Option<Line> {
         Some( Line {
             color: (*self.state[1].color).into(),
-            width: self.state[1].width.into(),
+            width: self.state[1].width,
         } )
     }
 }
@@ -115,7 +106,7 @@ impl StyleSheet for PaneGrid {
 #[derive(Clone, Debug)]
 pub struct Hovered {
     /// Background of the hovered region.
-    background: Arc<Color>,
+    background: Background,
 
     /// Border of the hovered region.
     border: Arc<Border>,
@@ -123,20 +114,29 @@ pub struct Hovered {
 
 impl Hovered {
     /// Attempts to create a theme from its serialized version.
-    fn from(serial: &serial::Hovered, theme: &Theme) -> Result<Self, ()> {
-        // Get the background color.
-        let background = match theme.color.get(&serial.background) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+    fn from(serial: &serial::Hovered, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background color or gradient.
+        let background = theme.getbackground(&serial.background, "background", &mut errors);
 
         // Get the border.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
+        let border = theme.getborder(&serial.border, "border", &mut errors);
 
-        Ok( Hovered { background, border, } )
+        match (background, border) {
+            (Some(background), Some(border)) => Ok( Hovered { background, border, } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the region to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Hovered {
+        serial::Hovered { background: export.background(&self.background), border: export.border(&self.border) }
+    }
+
+    /// Remaps the colors of the region.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Hovered { background: remap.background(&self.background), border: remap.border(&self.border) }
     }
 }
 
@@ -153,13 +153,23 @@ pub struct State {
 
 impl State {
     /// Attempts to create a theme from its serialized version.
-    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, ()> {
-        // Get the background color.
-        let color = match theme.color.get(&serial.color) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        Ok( State { color, width: serial.width } )
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the line color.
+        match theme.getcolor(&serial.color, "color", &mut errors) {
+            Some(color) => Ok( State { color, width: serial.width } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State { color: export.color(&self.color), width: self.width }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State { color: remap.color(&self.color), width: self.width }
     }
 }
@@ -2,6 +2,9 @@
 
 
 
+use crate::serial::{ BackgroundRef, BorderRef, ColorRef };
+use crate::theme::graph::Link;
+
 use serde_derive::{ Deserialize, Serialize };
 
 
@@ -20,11 +23,11 @@ pub struct PaneGrid {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Hovered {
-    /// Backgroud color of the region.
-    pub background: String, 
+    /// Backgroud color or gradient of the region.
+    pub background: BackgroundRef,
 
     /// Border of the region.
-    pub border: String,
+    pub border: BorderRef,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -42,7 +45,7 @@ pub enum HoveredComponent {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct State {
     /// The color of the line.
-    pub color: String, 
+    pub color: ColorRef,
 
     /// Width of the line.
     pub width: f32,
@@ -60,3 +63,27 @@ pub enum LineComponent {
     /// The line state is not defined.
     None,
 }
+
+impl crate::theme::graph::Component for HoveredComponent {
+    type Serial = Hovered;
+
+    fn link(&self) -> Link<'_, Hovered> {
+        match self {
+            HoveredComponent::Defined( state ) => Link::Defined( state ),
+            HoveredComponent::Inherited( name ) => Link::Inherited( name ),
+            HoveredComponent::None => Link::None,
+        }
+    }
+}
+
+impl crate::theme::graph::Component for LineComponent {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            LineComponent::Defined( state ) => Link::Defined( state ),
+            LineComponent::Inherited( name ) => Link::Inherited( name ),
+            LineComponent::None => Link::None,
+        }
+    }
+}
@@ -6,13 +6,16 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Border, Color, Theme, };
+use crate::{ Background, Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
 
 use iced::widget::pick_list::{ Appearance, StyleSheet, };
 
 use serial::{ MenuComponent, StateComponent };
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
 
 
 
@@ -26,66 +29,59 @@ pub struct Picklist {
 }
 
 impl Picklist {
-    /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::Picklist, theme: &Theme) -> Result<Self, ()> {
-        // Get all the themes.
-        let active   = Self::state( &serial.active  , theme, 0 )?;
-        let hovered  = Self::state( &serial.hovered , theme, 1 )?;
-
-        // Get the menu style.
-        let menu = Self::menu( &serial.menu, theme )?;
-        
-        // Find the first state theme that is not None.
-        let default = match (&active, &hovered) {
-            (Some(d), _) => d.clone(),
-            (_, Some(d)) => d.clone(),
-
-            _ => return Err(()),
-        };
-
-        Ok(Picklist {
-            state: [
-                active.unwrap_or(default.clone()),
-                hovered.unwrap_or(default.clone()),
-            ],
-
-            menu,
-        })
-    }
+    /// Names of the pick list states, in order.
+    pub(crate) const STATES: [&'static str; 2] = [ "active", "hovered", ];
 
-    fn state(serial: &serial::StateComponent, theme: &Theme, index: usize) -> Result<Option<Arc<State>>, ()> {
-        match serial {
-            StateComponent::Defined( state ) => Ok( Some( Arc::new( State::from(&state, &theme)? ) ) ),
+    /// Name of the pick list menu.
+    pub(crate) const MENU: [&'static str; 1] = [ "menu", ];
 
-            StateComponent::Inherited( name ) => match theme.picklist.get( name ) {
-                Some( picklist ) => Ok( Some( picklist.state[index].clone() ) ),
-                _ => Err(()),
-            },
+    /// Collects the states and menus of the serialized pick lists for inheritance resolution.
+    pub(crate) fn groups(serial: &HashMap<String, serial::Picklist>) -> (Group<'_, StateComponent, State>, Group<'_, MenuComponent, Menu>) {
+        let mut states = Group::new( "picklist", &Self::STATES, State::from );
+        let mut menus = Group::new( "picklist", &Self::MENU, Menu::from );
 
-            StateComponent::None => Ok( None ),
+        for (name, picklist) in serial {
+            states.insert( name, vec![ &picklist.active, &picklist.hovered ] );
+            menus.insert( name, vec![ &picklist.menu ] );
         }
+
+        (states, menus)
+    }
+
+    /// Creates a theme from its resolved states and menu.
+    pub(crate) fn create(name: &str, states: &Group<StateComponent, State>, menus: &Group<MenuComponent, Menu>) -> Option<Self> {
+        let state = states.get(name)?;
+        let menu = menus.get(name)?.pop()?;
+
+        Some( Picklist { state: state.try_into().ok()?, menu } )
     }
 
-    fn menu(serial: &serial::MenuComponent, theme: &Theme) -> Result<Arc<Menu>, ()> {
-        match serial {
-            MenuComponent::Defined( state ) => Ok( Arc::new( Menu::from(&state, &theme)? ) ),
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Picklist {
+        serial::Picklist {
+            active: StateComponent::Defined( self.state[0].serial(export) ),
+            hovered: StateComponent::Defined( self.state[1].serial(export) ),
+            menu: MenuComponent::Defined( self.menu.serial(export) ),
+        }
+    }
 
-            MenuComponent::Inherited( name ) => match theme.picklist.get( name ) {
-                Some( picklist ) => Ok( picklist.menu.clone() ),
-                _ => Err(()),
-            },
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Picklist {
+            state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)),
+            menu: remap.shared(&self.menu, Menu::remap),
         }
     }
 }
 
-impl Into<iced::theme::PickList> for Picklist {
-    fn into(self) -> iced::theme::PickList {
+impl From<Picklist> for iced::theme::PickList {
+    fn from(picklist: Picklist) -> Self {
         use std::rc::Rc;
 
         // Create the new RC.
-        let rc = Rc::new(self);
+        let rc = Rc::new(picklist);
 
-        iced::theme::PickList::Custom( rc.clone(), rc.clone() )
+        iced::theme::PickList::Custom( rc.clone(), rc )
     }
 }
 
@@ -96,7 +92,7 @@ impl StyleSheet for Picklist {
         Appearance {
             text_color: (*self.state[0].text).into(),
             placeholder_color: (*self.state[0].placeholder).into(),
-            background: (*self.state[0].background).into(),
+            background: (&self.state[0].background).into(),
             border_radius: self.state[0].border.radius,
             border_width: self.state[0].border.width,
             border_color: (*self.state[0].border.color).into(),
@@ -108,7 +104,7 @@ impl StyleSheet for Picklist {
         Appearance {
             text_color: (*self.state[1].text).into(),
             placeholder_color: (*self.state[1].placeholder).into(),
-            background: (*self.state[1].background).into(),
+            background: (&self.state[1].background).into(),
             border_radius: self.state[1].border.radius,
             border_width: self.state[1].border.width,
             border_color: (*self.state[1].border.color).into(),
@@ -123,12 +119,12 @@ impl iced::overlay::menu::StyleSheet for Picklist {
     fn appearance(&self, _: &Self::Style) -> iced::overlay::menu::Appearance {
         iced::overlay::menu::Appearance {
             text_color: (*self.menu.text[0]).into(),
-            background: (*self.menu.background[0]).into(),
+            background: (&self.menu.background[0]).into(),
             border_radius: self.menu.border.radius,
             border_width: self.menu.border.width,
             border_color: (*self.menu.border.color).into(),
             selected_text_color: (*self.menu.text[1]).into(),
-            selected_background: (*self.menu.background[1]).into(),
+            selected_background: (&self.menu.background[1]).into(),
         }
     }
 }
@@ -136,8 +132,8 @@ impl iced::overlay::menu::StyleSheet for Picklist {
 
 #[derive(Clone, Debug)]
 pub struct State {
-    /// Background color.
-    pub background: Arc<Color>,
+    /// Background color or gradient.
+    pub background: Background,
 
     /// Text color.
     pub text: Arc<Color>,
@@ -154,45 +150,58 @@ pub struct State {
 
 impl State {
     /// Attempts to create a theme from its serialized version.
-    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, ()> {
-        // Get the background color.
-        let background = match theme.color.get(&serial.background) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background color or gradient.
+        let background = theme.getbackground(&serial.background, "background", &mut errors);
 
         // Get the text color.
-        let text = match theme.color.get(&serial.text) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let text = theme.getcolor(&serial.text, "text", &mut errors);
 
         // Get the placeholder color.
-        let placeholder = match theme.color.get(&serial.placeholder) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        // Get the background color.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
-
-        let handle = match theme.color.get(&serial.handle) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        Ok( State { background, text, placeholder, border, handle } )
+        let placeholder = theme.getcolor(&serial.placeholder, "placeholder", &mut errors);
+
+        // Get the border.
+        let border = theme.getborder(&serial.border, "border", &mut errors);
+
+        // Get the handle color.
+        let handle = theme.getcolor(&serial.handle, "handle", &mut errors);
+
+        match (background, text, placeholder, border, handle) {
+            (Some(background), Some(text), Some(placeholder), Some(border), Some(handle)) => Ok( State { background, text, placeholder, border, handle } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            background: export.background(&self.background),
+            text: export.color(&self.text),
+            placeholder: export.color(&self.placeholder),
+            border: export.border(&self.border),
+            handle: export.color(&self.handle),
+        }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            background: remap.background(&self.background),
+            text: remap.color(&self.text),
+            placeholder: remap.color(&self.placeholder),
+            border: remap.border(&self.border),
+            handle: remap.color(&self.handle),
+        }
     }
 }
 
 
 #[derive(Clone, Debug)]
 pub struct Menu {
-    /// Key to the background color.
-    pub background: [Arc<Color>; 2],
+    /// Key to the background color or gradient.
+    pub background: [Background; 2],
 
     /// Key to the text color.
     pub text: [Arc<Color>; 2],
@@ -203,35 +212,43 @@ pub struct Menu {
 
 impl Menu {
     /// Attempts to create a theme from its serialized version.
-    fn from(serial: &serial::Menu, theme: &Theme) -> Result<Self, ()> {
-        // Get the background colors.
-        let background = match theme.color.get(&serial.background) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        let sbackground = match theme.color.get(&serial.sbackground) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+    fn from(serial: &serial::Menu, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
 
-        // Get the text color.
-        let text = match theme.color.get(&serial.text) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        let stext = match theme.color.get(&serial.stext) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        // Get the background color.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
-
-        Ok( Menu { background: [background, sbackground], text: [text, stext], border } )
+        // Get the background colors or gradients.
+        let background = theme.getbackground(&serial.background, "background", &mut errors);
+        let sbackground = theme.getbackground(&serial.sbackground, "sbackground", &mut errors);
+
+        // Get the text colors.
+        let text = theme.getcolor(&serial.text, "text", &mut errors);
+        let stext = theme.getcolor(&serial.stext, "stext", &mut errors);
+
+        // Get the border.
+        let border = theme.getborder(&serial.border, "border", &mut errors);
+
+        match (background, sbackground, text, stext, border) {
+            (Some(background), Some(sbackground), Some(text), Some(stext), Some(border)) => Ok( Menu { background: [background, sbackground], text: [text, stext], border } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the menu to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Menu {
+        serial::Menu {
+            background: export.background(&self.background[0]),
+            text: export.color(&self.text[0]),
+            border: export.border(&self.border),
+            sbackground: export.background(&self.background[1]),
+            stext: export.color(&self.text[1]),
+        }
+    }
+
+    /// Remaps the colors of the menu.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Menu {
+            background: [ remap.background(&self.background[0]), remap.background(&self.background[1]) ],
+            text: [ remap.color(&self.text[0]), remap.color(&self.text[1]) ],
+            border: remap.border(&self.border),
+        }
     }
 }
@@ -2,6 +2,9 @@
 
 
 
+use crate::serial::{ BackgroundRef, BorderRef, ColorRef };
+use crate::theme::graph::Link;
+
 use serde_derive::{ Deserialize, Serialize };
 
 
@@ -20,38 +23,38 @@ pub struct Picklist {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct State {
-    /// Key to the background color.
-    pub background: String,
+    /// Reference to the background color or gradient.
+    pub background: BackgroundRef,
 
-    /// Key to the text color.
-    pub text: String,
+    /// Reference to the text color.
+    pub text: ColorRef,
 
-    /// Key to the placeholder color.
-    pub placeholder: String,
+    /// Reference to the placeholder color.
+    pub placeholder: ColorRef,
 
-    /// Key to the border theme.
-    pub border: String,
+    /// Reference to the border theme.
+    pub border: BorderRef,
 
     /// Handle color.
-    pub handle: String,
+    pub handle: ColorRef,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Menu {
-    /// Key to the background color.
-    pub background: String,
+    /// Reference to the background color or gradient.
+    pub background: BackgroundRef,
 
-    /// Key to the text color.
-    pub text: String,
+    /// Reference to the text color.
+    pub text: ColorRef,
 
-    /// Key to the border theme.
-    pub border: String,
+    /// Reference to the border theme.
+    pub border: BorderRef,
 
-    /// Key to the selected background color.
-    pub sbackground: String,
+    /// Reference to the selected background color or gradient.
+    pub sbackground: BackgroundRef,
 
-    /// Key to the selected text color.
-    pub stext: String,
+    /// Reference to the selected text color.
+    pub stext: ColorRef,
 }
 
 
@@ -75,3 +78,26 @@ pub enum MenuComponent {
     /// The button state is inherited from another button theme.
     Inherited( String ),
 }
+
+impl crate::theme::graph::Component for StateComponent {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            StateComponent::Defined( state ) => Link::Defined( state ),
+            StateComponent::Inherited( name ) => Link::Inherited( name ),
+            StateComponent::None => Link::None,
+        }
+    }
+}
+
+impl crate::theme::graph::Component for MenuComponent {
+    type Serial = Menu;
+
+    fn link(&self) -> Link<'_, Menu> {
+        match self {
+            MenuComponent::Defined( state ) => Link::Defined( state ),
+            MenuComponent::Inherited( name ) => Link::Inherited( name ),
+        }
+    }
+}
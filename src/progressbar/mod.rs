@@ -6,7 +6,7 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Color, Theme, };
+use crate::{ Background, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
 
 use iced::{
     BorderRadius,
@@ -16,17 +16,15 @@ use iced::{
     }
 };
 
-use std::sync::Arc;
-
 
 
 #[derive(Clone, Debug)]
 pub struct ProgressBar {
-    /// Background color.
-    pub background: Arc<Color>,
+    /// Background color or gradient.
+    pub background: Background,
 
-    /// Bar color.
-    pub bar: Arc<Color>,
+    /// Bar color or gradient.
+    pub bar: Background,
 
     /// Border radius.
     pub radius: BorderRadius,
@@ -34,20 +32,29 @@ pub struct ProgressBar {
 
 impl ProgressBar {
     /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::ProgressBar, theme: &Theme) -> Result<Self, ()> {
-        // Get the color of the progress bar background.
-        let background = match theme.color.get(&serial.background) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        // Get the border of the progress bar bar.
-        let bar = match theme.color.get(&serial.bar) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        Ok( ProgressBar { background, bar, radius: BorderRadius::from( serial.radius ) } )
+    pub(crate) fn create(serial: &serial::ProgressBar, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the color or gradient of the progress bar background.
+        let background = theme.getbackground(&serial.background, "background", &mut errors);
+
+        // Get the color or gradient of the progress bar bar.
+        let bar = theme.getbackground(&serial.bar, "bar", &mut errors);
+
+        match (background, bar) {
+            (Some(background), Some(bar)) => Ok( ProgressBar { background, bar, radius: serial.radius.convert() } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::ProgressBar {
+        serial::ProgressBar { background: export.background(&self.background), bar: export.background(&self.bar), radius: self.radius.into() }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        ProgressBar { background: remap.background(&self.background), bar: remap.background(&self.bar), radius: self.radius }
     }
 }
 
@@ -56,8 +63,8 @@ impl StyleSheet for ProgressBar {
 
     fn appearance(&self, _: &Self::Style) -> Appearance {
         Appearance {
-            background: (*self.background).into(),
-            bar: (*self.bar).into(),
+            background: (&self.background).into(),
+            bar: (&self.bar).into(),
             border_radius: self.radius,
         }
     }
@@ -2,18 +2,20 @@
 
 
 
+use crate::serial::{ BackgroundRef, Radius };
+
 use serde_derive::{ Deserialize, Serialize };
 
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ProgressBar {
-    /// Key to the background color.
-    pub background: String,
+    /// Reference to the background color or gradient.
+    pub background: BackgroundRef,
 
-    /// Key to the bar color.
-    pub bar: String,
+    /// Reference to the bar color or gradient.
+    pub bar: BackgroundRef,
 
     /// Border radius.
-    pub radius: f32,
+    pub radius: Radius,
 }
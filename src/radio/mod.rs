@@ -2,15 +2,154 @@
 
 
 
-mod serial;
-mod style;
+pub(crate) mod serial;
 
 
 
-pub use self::serial::{
-    Radio as Serial,
-    RadioState as StateSerial,
-    Component,
+use crate::{ Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
+
+use iced::widget::radio::{ Appearance, StyleSheet, };
+
+use serial::Component;
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
 };
 
-pub use self::style::{ Theme };
+
+
+#[derive(Clone, Debug)]
+pub struct Radio {
+    /// State Themes of the radio.
+    /// In order: active, hovered, active unselected, hovered unselected.
+    pub state: [Arc<State>; 4],
+}
+
+impl Radio {
+    /// Names of the radio states, in order.
+    pub(crate) const STATES: [&'static str; 4] = [ "active", "hovered", "uactive", "uhovered", ];
+
+    /// States the unselected states fall back on: `uhovered` on `uactive` and `uactive` on `active`.
+    const FALLBACKS: [Option<usize>; 4] = [ None, None, Some(0), Some(2), ];
+
+    /// Collects the states of the serialized radios for inheritance resolution.
+    pub(crate) fn group(serial: &HashMap<String, serial::Radio>) -> Group<'_, Component, State> {
+        let mut group = Group::new( "radio", &Self::STATES, State::from ).fallbacks( &Self::FALLBACKS );
+
+        for (name, radio) in serial {
+            group.insert( name, vec![ &radio.active, &radio.hovered, &radio.uactive, &radio.uhovered ] );
+        }
+
+        group
+    }
+
+    /// Creates a theme from its resolved states.
+    pub(crate) fn create(name: &str, group: &Group<Component, State>) -> Option<Self> {
+        let state = group.get(name)?;
+
+        Some( Radio { state: state.try_into().ok()? } )
+    }
+
+    /// Builds the appearance of the given state.
+    fn appearance(&self, index: usize) -> Appearance {
+        Appearance {
+            background: (*self.state[index].background).into(),
+            dot_color: (*self.state[index].dot).into(),
+            border_width: self.state[index].border.width,
+            border_color: (*self.state[index].border.color).into(),
+            text_color: self.state[index].text.as_ref().map(|c| (**c).into()),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Radio {
+        let state = |i: usize| serial::Component::Defined( self.state[i].serial(export) );
+
+        serial::Radio { active: state(0), hovered: state(1), uactive: state(2), uhovered: state(3) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Radio { state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)) }
+    }
+}
+
+impl StyleSheet for Radio {
+    type Style = iced::Theme;
+
+    fn active(&self, _: &Self::Style, selected: bool) -> Appearance {
+        match selected {
+            true => self.appearance(0),
+            false => self.appearance(2),
+        }
+    }
+
+    fn hovered(&self, _: &Self::Style, selected: bool) -> Appearance {
+        match selected {
+            true => self.appearance(1),
+            false => self.appearance(3),
+        }
+    }
+}
+
+
+
+#[derive(Clone, Debug)]
+pub struct State {
+    /// Background color.
+    pub(crate) background: Arc<Color>,
+
+    /// Dot color.
+    pub(crate) dot: Arc<Color>,
+
+    /// Text color. Uses the default text color if not set.
+    pub(crate) text: Option<Arc<Color>>,
+
+    /// Border theme. The radius is ignored, as radios are always round.
+    pub(crate) border: Arc<Border>,
+}
+
+impl State {
+    /// Attempts to create a theme from its serialized version.
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background color.
+        let background = theme.getcolor(&serial.background, "background", &mut errors);
+
+        // Get the dot color.
+        let dot = theme.getcolor(&serial.dotcolor, "dotcolor", &mut errors);
+
+        // Get the text color, if any.
+        let text = serial.textcolor.as_ref().map(|key| theme.getcolor(key, "textcolor", &mut errors));
+
+        // Get the border.
+        let border = theme.getborder(&serial.border, "border", &mut errors);
+
+        match (background, dot, border) {
+            (Some(background), Some(dot), Some(border)) if errors.is_empty() => Ok( State { background, dot, text: text.flatten(), border } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            background: export.color(&self.background),
+            dotcolor: export.color(&self.dot),
+            textcolor: self.text.as_ref().map(|text| export.color(text)),
+            border: export.border(&self.border),
+        }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            background: remap.color(&self.background),
+            dot: remap.color(&self.dot),
+            text: self.text.as_ref().map(|text| remap.color(text)),
+            border: remap.border(&self.border),
+        }
+    }
+}
@@ -2,43 +2,64 @@
 
 
 
+use crate::serial::{ BorderRef, ColorRef };
+use crate::theme::graph::Link;
+
 use serde_derive::{ Deserialize, Serialize };
 
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Radio {
-    /// Active state.
+    /// Active selected state.
     pub active: Component,
 
-    /// Hovered state.
+    /// Hovered selected state.
     pub hovered: Component,
+
+    /// Active unselected state.
+    pub uactive: Component,
+
+    /// Hovered unselected state.
+    pub uhovered: Component,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct RadioState {
-    /// Key to the background color.
-    pub background: String,
+pub struct State {
+    /// Reference to the background color.
+    pub background: ColorRef,
 
-    /// Key to the dot color.
-    pub dotcolor: String,
+    /// Reference to the dot color.
+    pub dotcolor: ColorRef,
 
-    /// Key to the text color.
-    pub textcolor: Option<String>,
+    /// Reference to the text color.
+    pub textcolor: Option<ColorRef>,
 
-    /// Key to the border theme.
-    pub border: String,
+    /// Reference to the border theme.
+    pub border: BorderRef,
 }
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Component {
     /// The radio state is defined.
-    Defined(RadioState),
+    Defined(State),
 
-    /// The button state is inherited from another radio theme.
+    /// The radio state is inherited from another radio theme.
     Inherited(String),
 
     /// The radio state is not defined.
     None,
 }
+
+impl crate::theme::graph::Component for Component {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            Component::Defined( state ) => Link::Defined( state ),
+            Component::Inherited( name ) => Link::Inherited( name ),
+            Component::None => Link::None,
+        }
+    }
+}
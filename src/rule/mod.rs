@@ -2,10 +2,71 @@
 
 
 
-mod serial;
-mod style;
+pub(crate) mod serial;
 
 
 
-pub use self::serial::Rule as Serial;
-pub use self::style::Theme;
+use crate::{ Color, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
+
+use iced::{
+    BorderRadius,
+
+    widget::rule::{
+        Appearance, FillMode, StyleSheet,
+    },
+};
+
+use std::sync::Arc;
+
+
+
+#[derive(Clone, Debug)]
+pub struct Rule {
+    /// Color of the rule.
+    pub color: Arc<Color>,
+
+    /// Fill mode of the rule.
+    pub fillmode: FillMode,
+
+    /// Radius of the rule.
+    pub radius: BorderRadius,
+
+    /// Width of the rule.
+    pub width: u16,
+}
+
+impl Rule {
+    /// Attempts to create a theme from its serialized version.
+    pub(crate) fn create(serial: &serial::Rule, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the color of the rule.
+        match theme.getcolor(&serial.color, "color", &mut errors) {
+            Some(color) => Ok( Rule { color, fillmode: serial.fillmode.convert(), radius: serial.radius.convert(), width: serial.width } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Rule {
+        serial::Rule { color: export.color(&self.color), fillmode: self.fillmode.into(), radius: self.radius.into(), width: self.width }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Rule { color: remap.color(&self.color), fillmode: self.fillmode, radius: self.radius, width: self.width }
+    }
+}
+
+impl StyleSheet for Rule {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _: &Self::Style) -> Appearance {
+        Appearance {
+            color: (*self.color).into(),
+            width: self.width,
+            radius: self.radius,
+            fill_mode: self.fillmode,
+        }
+    }
+}
@@ -2,20 +2,22 @@
 
 
 
+use crate::serial::{ ColorRef, Radius };
+
 use serde_derive::{ Deserialize, Serialize };
 
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Rule {
-    /// Key to the color.
-    pub color: String,
+    /// Reference to the color.
+    pub color: ColorRef,
 
     /// Fill mode of the rule.
     pub fillmode: FillMode,
 
     /// Radius of the rule.
-    pub radius: f32,
+    pub radius: Radius,
 
     /// Width of the rule.
     pub width: u16,
@@ -25,13 +27,21 @@ pub struct Rule {
 /// Copied from `iced` for serialization.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum FillMode {
+    /// Padding on each end of the rule, in pixels.
     AsymmetricPadding(u16, u16),
+
+    /// The rule fills the whole space.
     Full,
+
+    /// Same padding on both ends of the rule, in pixels.
     Padded(u16),
+
+    /// The rule fills the given percentage of the space, centered.
     Percent(f32),
 }
 
 impl FillMode {
+    /// Converts the fill mode to its `iced` version.
     pub fn convert(&self) -> iced::widget::rule::FillMode {
         match *self {
             FillMode::AsymmetricPadding(a, b) => iced::widget::rule::FillMode::AsymmetricPadding(a, b),
@@ -41,3 +51,14 @@ impl FillMode {
         }
     }
 }
+
+impl From<iced::widget::rule::FillMode> for FillMode {
+    fn from(fillmode: iced::widget::rule::FillMode) -> Self {
+        match fillmode {
+            iced::widget::rule::FillMode::AsymmetricPadding(a, b) => FillMode::AsymmetricPadding(a, b),
+            iced::widget::rule::FillMode::Full => FillMode::Full,
+            iced::widget::rule::FillMode::Padded(a) => FillMode::Padded(a),
+            iced::widget::rule::FillMode::Percent(a) => FillMode::Percent(a),
+        }
+    }
+}
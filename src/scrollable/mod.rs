@@ -6,13 +6,16 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Border, Color, Theme, };
+use crate::{ Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
 
 use iced::widget::scrollable::{ StyleSheet, Scrollbar, Scroller, };
 
 use serial::Component;
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
 
 
 
@@ -24,42 +27,37 @@ pub struct Scrollable {
 }
 
 impl Scrollable {
-    /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::Scrollable, theme: &Theme) -> Result<Self, ()> {
-        // Get all the themes.
-        let active   = Self::state( &serial.active  , theme, 0 )?;
-        let hovered  = Self::state( &serial.hovered , theme, 1 )?;
-        let dragging = Self::state( &serial.dragging, theme, 2 )?;
-        
-        // Find the first state theme that is not None.
-        let default = match (&active, &hovered, &dragging) {
-            (Some(d), _, _) => d.clone(),
-            (_, Some(d), _) => d.clone(),
-            (_, _, Some(d)) => d.clone(),
-
-            _ => return Err(()),
-        };
-
-        Ok(Scrollable {
-            state: [
-                active.unwrap_or(default.clone()),
-                hovered.unwrap_or(default.clone()),
-                dragging.unwrap_or(default.clone()),
-            ],
-        })
+    /// Names of the scrollable states, in order.
+    pub(crate) const STATES: [&'static str; 3] = [ "active", "hovered", "dragging", ];
+
+    /// Collects the states of the serialized scrollables for inheritance resolution.
+    pub(crate) fn group(serial: &HashMap<String, serial::Scrollable>) -> Group<'_, Component, State> {
+        let mut group = Group::new( "scrollable", &Self::STATES, State::from );
+
+        for (name, scrollable) in serial {
+            group.insert( name, vec![ &scrollable.active, &scrollable.hovered, &scrollable.dragging ] );
+        }
+
+        group
     }
 
-    fn state(serial: &Component, theme: &Theme, index: usize) -> Result<Option<Arc<State>>, ()> {
-        match serial {
-            Component::Defined( state ) => Ok( Some( Arc::new( State::from(&state, &theme)? ) ) ),
+    /// Creates a theme from its resolved states.
+    pub(crate) fn create(name: &str, group: &Group<Component, State>) -> Option<Self> {
+        let state = group.get(name)?;
 
-            Component::Inherited( name ) => match theme.scrollable.get( name ) {
-                Some( scrollable ) => Ok( Some( scrollable.state[index].clone() ) ),
-                _ => Err(()),
-            },
+        Some( Scrollable { state: state.try_into().ok()? } )
+    }
 
-            Component::None => Ok( None ),
-        }
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Scrollable {
+        let state = |i: usize| serial::Component::Defined( self.state[i].serial(export) );
+
+        serial::Scrollable { active: state(0), hovered: state(1), dragging: state(2) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Scrollable { state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)) }
     }
 }
 
@@ -97,6 +95,22 @@ impl StyleSheet for Scrollable {
             }
         }
     }
+
+    fn dragging(&self, _: &Self::Style) -> Scrollbar {
+        Scrollbar {
+            background: Some( (*self.state[2].color).into() ),
+            border_radius: self.state[2].border.radius,
+            border_width: self.state[2].border.width,
+            border_color: (*self.state[2].border.color).into(),
+
+            scroller: Scroller {
+                color: (*self.state[2].scolor).into(),
+                border_radius: self.state[2].sborder.radius,
+                border_width: self.state[2].sborder.width,
+                border_color: (*self.state[2].sborder.color).into(),
+            }
+        }
+    }
 }
 
 
@@ -118,31 +132,44 @@ pub struct State {
 
 impl State {
     /// Attempts to create a theme from its serialized version.
-    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, ()> {
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
         // Get the scrollable color.
-        let color = match theme.color.get(&serial.color) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let color = theme.getcolor(&serial.color, "color", &mut errors);
 
         // Get the scrollable border.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
+        let border = theme.getborder(&serial.border, "border", &mut errors);
 
         // Get the scroller color.
-        let scolor = match theme.color.get(&serial.scolor) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let scolor = theme.getcolor(&serial.scolor, "scolor", &mut errors);
 
         // Get the scroller border.
-        let sborder = match theme.border.get(&serial.sborder) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
+        let sborder = theme.getborder(&serial.sborder, "sborder", &mut errors);
+
+        match (color, border, scolor, sborder) {
+            (Some(color), Some(border), Some(scolor), Some(sborder)) => Ok( State { color, border, scolor, sborder } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            color: export.color(&self.color),
+            border: export.border(&self.border),
+            scolor: export.color(&self.scolor),
+            sborder: export.border(&self.sborder),
+        }
+    }
 
-        Ok( State { color, border, scolor, sborder } )
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            color: remap.color(&self.color),
+            border: remap.border(&self.border),
+            scolor: remap.color(&self.scolor),
+            sborder: remap.border(&self.sborder),
+        }
     }
 }
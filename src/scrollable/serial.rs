@@ -2,6 +2,9 @@
 
 
 
+use crate::serial::{ BorderRef, ColorRef };
+use crate::theme::graph::Link;
+
 use serde_derive::{ Deserialize, Serialize };
 
 
@@ -20,17 +23,17 @@ pub struct Scrollable {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct State {
-    /// Key to the background color.
-    pub color: String,
+    /// Reference to the background color.
+    pub color: ColorRef,
 
-    /// Key to the border theme.
-    pub border: String,
+    /// Reference to the border theme.
+    pub border: BorderRef,
 
-    /// Key to the scroller color.
-    pub scolor: String,
+    /// Reference to the scroller color.
+    pub scolor: ColorRef,
 
-    /// Key to the scroller border.
-    pub sborder: String,
+    /// Reference to the scroller border.
+    pub sborder: BorderRef,
 }
 
 
@@ -45,3 +48,15 @@ pub enum Component {
     /// The button state is not defined.
     None,
 }
+
+impl crate::theme::graph::Component for Component {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            Component::Defined( state ) => Link::Defined( state ),
+            Component::Inherited( name ) => Link::Inherited( name ),
+            Component::None => Link::None,
+        }
+    }
+}
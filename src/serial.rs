@@ -2,13 +2,124 @@
 
 
 
-pub(crate) use crate::border::serial::Border;
+pub(crate) use crate::application::serial::Application;
+pub(crate) use crate::border::serial::{ Border, Radius };
 pub(crate) use crate::button::serial::Button;
-pub(crate) use crate::color::Color;
+pub(crate) use crate::checkbox::serial::Checkbox;
+pub(crate) use crate::color::serial::Color;
 pub(crate) use crate::container::serial::Container;
+pub(crate) use crate::gradient::serial::Gradient;
 pub(crate) use crate::panegrid::serial::PaneGrid;
 pub(crate) use crate::picklist::serial::Picklist;
 pub(crate) use crate::progressbar::serial::ProgressBar;
+pub(crate) use crate::radio::serial::Radio;
+pub(crate) use crate::rule::serial::Rule;
 pub(crate) use crate::scrollable::serial::Scrollable;
+pub(crate) use crate::slider::serial::Slider;
+pub(crate) use crate::svg::serial::Svg;
+pub(crate) use crate::text::serial::Text;
 pub(crate) use crate::textinput::serial::TextInput;
+pub(crate) use crate::toggler::serial::Toggler;
 pub(crate) use crate::tooltip::serial::Tooltip;
+
+
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{ self, MapAccess, SeqAccess, Visitor, value::{ MapAccessDeserializer, SeqAccessDeserializer } },
+};
+
+use serde_derive::Deserialize;
+
+use std::{ fmt, marker::PhantomData };
+
+
+
+/// Reference to a value of the theme by its key, or a value defined in place.
+/// Deserializes a string as a key and any other value as an inline value.
+#[derive(Clone, Debug)]
+pub enum Ref<T> {
+    /// Key of the value in the theme.
+    Key( String ),
+
+    /// Value defined in place.
+    Inline( Box<T> ),
+}
+
+/// Reference to a color, or an inline color or derived color.
+/// A string is always a key, so inline colors written as strings are wrapped
+/// (e.g. `Color("#3d8fd9")` or `Color("lighten(accent, 0.1)")`).
+pub type ColorRef = Ref<Color>;
+
+/// Reference to a color or a gradient, or an inline color or derived color.
+/// Color keys take precedence over gradient keys.
+pub type BackgroundRef = Ref<Color>;
+
+/// Reference to a border, or an inline border.
+pub type BorderRef = Ref<Border>;
+
+/// Value that can be written inline in place of a key.
+pub trait Inline: Serialize {
+    /// Serializes the value so that it is not read back as a key.
+    fn serialize_inline<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+}
+
+impl Inline for Border {}
+
+impl<T: Inline> Serialize for Ref<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self, serializer.is_human_readable()) {
+            (Ref::Key(key), true) => serializer.serialize_str(key),
+            (Ref::Inline(value), true) => value.serialize_inline(serializer),
+            (Ref::Key(key), false) => serializer.serialize_newtype_variant("Ref", 0, "Key", key),
+            (Ref::Inline(value), false) => serializer.serialize_newtype_variant("Ref", 1, "Inline", value),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Ref<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Binary formats are not self describing and use a tagged enum.
+        if !deserializer.is_human_readable() {
+            return match Tagged::deserialize(deserializer)? {
+                Tagged::Key(key) => Ok( Ref::Key(key) ),
+                Tagged::Inline(value) => Ok( Ref::Inline( Box::new(value) ) ),
+            };
+        }
+
+        deserializer.deserialize_any( RefVisitor(PhantomData) )
+    }
+}
+
+/// Reference of non self describing formats.
+#[derive(Deserialize)]
+#[serde(rename = "Ref")]
+enum Tagged<T> {
+    Key( String ),
+    Inline( T ),
+}
+
+/// Visitor of a reference in self describing formats.
+struct RefVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RefVisitor<T> {
+    type Value = Ref<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a key or an inline value")
+    }
+
+    fn visit_str<E: de::Error>(self, key: &str) -> Result<Ref<T>, E> {
+        Ok( Ref::Key(key.into()) )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Ref<T>, A::Error> {
+        T::deserialize( SeqAccessDeserializer::new(seq) ).map(|value| Ref::Inline( Box::new(value) ))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Ref<T>, A::Error> {
+        T::deserialize( MapAccessDeserializer::new(map) ).map(|value| Ref::Inline( Box::new(value) ))
+    }
+}
@@ -1,16 +1,181 @@
 //! Slider theme.
+//! `iced` uses the same `StyleSheet` for horizontal and vertical sliders,
+//! so a `Slider` theme also styles a `VerticalSlider`.
 
 
 
-mod serial;
-mod style;
+pub(crate) mod serial;
 
 
 
-pub use self::serial::{
-    Slider as Serial,
-    SliderState as StateSerial,
-    Component,
+use crate::{ Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
+
+use iced::{
+    BorderRadius,
+
+    widget::slider::{
+        Appearance, Handle, HandleShape, Rail, StyleSheet,
+    },
+};
+
+use serial::Component;
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
 };
 
-pub use self::style::Theme;
+
+
+#[derive(Clone, Debug)]
+pub struct Slider {
+    /// State Themes of the slider.
+    /// In order: active, hovered, dragging.
+    pub state: [Arc<State>; 3],
+}
+
+impl Slider {
+    /// Names of the slider states, in order.
+    pub(crate) const STATES: [&'static str; 3] = [ "active", "hovered", "dragging", ];
+
+    /// Collects the states of the serialized sliders for inheritance resolution.
+    pub(crate) fn group(serial: &HashMap<String, serial::Slider>) -> Group<'_, Component, State> {
+        let mut group = Group::new( "slider", &Self::STATES, State::from );
+
+        for (name, slider) in serial {
+            group.insert( name, vec![ &slider.active, &slider.hovered, &slider.dragging ] );
+        }
+
+        group
+    }
+
+    /// Creates a theme from its resolved states.
+    pub(crate) fn create(name: &str, group: &Group<Component, State>) -> Option<Self> {
+        let state = group.get(name)?;
+
+        Some( Slider { state: state.try_into().ok()? } )
+    }
+
+    /// Builds the appearance of the given state.
+    fn appearance(&self, index: usize) -> Appearance {
+        Appearance {
+            rail: Rail {
+                colors: ( (*self.state[index].colors.0).into(), (*self.state[index].colors.1).into() ),
+                width: self.state[index].width,
+                border_radius: self.state[index].radius,
+            },
+
+            handle: Handle {
+                shape: self.state[index].shape,
+                color: (*self.state[index].hcolor).into(),
+                border_width: self.state[index].hborder.width,
+                border_color: (*self.state[index].hborder.color).into(),
+            },
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Slider {
+        let state = |i: usize| serial::Component::Defined( self.state[i].serial(export) );
+
+        serial::Slider { active: state(0), hovered: state(1), dragging: state(2) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Slider { state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)) }
+    }
+}
+
+impl StyleSheet for Slider {
+    type Style = iced::Theme;
+
+    fn active(&self, _: &Self::Style) -> Appearance {
+        self.appearance(0)
+    }
+
+    fn hovered(&self, _: &Self::Style) -> Appearance {
+        self.appearance(1)
+    }
+
+    fn dragging(&self, _: &Self::Style) -> Appearance {
+        self.appearance(2)
+    }
+}
+
+
+
+#[derive(Clone, Debug)]
+pub struct State {
+    /// Rail colors.
+    pub(crate) colors: (Arc<Color>, Arc<Color>),
+
+    /// Rail width.
+    pub(crate) width: f32,
+
+    /// Rail border radius.
+    pub(crate) radius: BorderRadius,
+
+    /// Border of the handle.
+    pub(crate) hborder: Arc<Border>,
+
+    /// Color of the handle.
+    pub(crate) hcolor: Arc<Color>,
+
+    /// Shape of the handle.
+    pub(crate) shape: HandleShape,
+}
+
+impl State {
+    /// Attempts to create a theme from its serialized version.
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the rail colors.
+        let colora = theme.getcolor(&serial.colors.0, "colors.0", &mut errors);
+        let colorb = theme.getcolor(&serial.colors.1, "colors.1", &mut errors);
+
+        // Get the handle border.
+        let hborder = theme.getborder(&serial.hborder, "hborder", &mut errors);
+
+        // Get the handle color.
+        let hcolor = theme.getcolor(&serial.hcolor, "hcolor", &mut errors);
+
+        match (colora, colorb, hborder, hcolor) {
+            (Some(colora), Some(colorb), Some(hborder), Some(hcolor)) => Ok( State {
+                colors: (colora, colorb),
+                width: serial.rwidth,
+                radius: serial.rradius.convert(),
+                hborder,
+                hcolor,
+                shape: serial.shape.convert(),
+            } ),
+
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            colors: (export.color(&self.colors.0), export.color(&self.colors.1)),
+            rwidth: self.width,
+            rradius: self.radius.into(),
+            hborder: export.border(&self.hborder),
+            hcolor: export.color(&self.hcolor),
+            shape: self.shape.into(),
+        }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            colors: (remap.color(&self.colors.0), remap.color(&self.colors.1)),
+            width: self.width,
+            radius: self.radius,
+            hborder: remap.border(&self.hborder),
+            hcolor: remap.color(&self.hcolor),
+            shape: self.shape,
+        }
+    }
+}
@@ -2,6 +2,9 @@
 
 
 
+use crate::serial::{ BorderRef, ColorRef, Radius };
+use crate::theme::graph::Link;
+
 use serde_derive::{ Deserialize, Serialize };
 
 
@@ -19,15 +22,21 @@ pub struct Slider {
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct SliderState {
-    /// Key to the rail colors.
-    pub colors: (String, String),
+pub struct State {
+    /// Reference to the rail colors.
+    pub colors: (ColorRef, ColorRef),
+
+    /// Width of the rail.
+    pub rwidth: f32,
+
+    /// Border radius of the rail.
+    pub rradius: Radius,
 
     /// Border of the handle.
-    pub hborder: String,
+    pub hborder: BorderRef,
 
     /// Color of the handle.
-    pub hcolor: String,
+    pub hcolor: ColorRef,
 
     /// Shape of the handle.
     pub shape: HandleShape,
@@ -37,7 +46,7 @@ pub struct SliderState {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Component {
     /// The slider state is defined.
-    Defined(SliderState),
+    Defined(State),
 
     /// The slider state is inherited from another slider theme.
     Inherited(String),
@@ -46,18 +55,43 @@ pub enum Component {
     None,
 }
 
+impl crate::theme::graph::Component for Component {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            Component::Defined( state ) => Link::Defined( state ),
+            Component::Inherited( name ) => Link::Inherited( name ),
+            Component::None => Link::None,
+        }
+    }
+}
+
 /// Copied from `iced` for serialization.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum HandleShape {
+    /// Circular handle with the given radius.
     Circle(f32),
-    Rectangle(u16, f32),
+
+    /// Rectangular handle with the given width and border radius.
+    Rectangle(u16, Radius),
 }
 
 impl HandleShape {
+    /// Converts the handle shape to its `iced` version.
     pub fn convert(&self) -> iced::widget::slider::HandleShape {
         match *self {
             HandleShape::Circle(radius) => iced::widget::slider::HandleShape::Circle { radius },
-            HandleShape::Rectangle(width, border_radius) => iced::widget::slider::HandleShape::Rectangle { width, border_radius },
+            HandleShape::Rectangle(width, border_radius) => iced::widget::slider::HandleShape::Rectangle { width, border_radius: border_radius.convert() },
+        }
+    }
+}
+
+impl From<iced::widget::slider::HandleShape> for HandleShape {
+    fn from(shape: iced::widget::slider::HandleShape) -> Self {
+        match shape {
+            iced::widget::slider::HandleShape::Circle { radius } => HandleShape::Circle(radius),
+            iced::widget::slider::HandleShape::Rectangle { width, border_radius } => HandleShape::Rectangle(width, border_radius.into()),
         }
     }
 }
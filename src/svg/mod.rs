@@ -0,0 +1,64 @@
+//! Svg theme.
+
+
+
+pub(crate) mod serial;
+
+
+
+use crate::{ Color, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
+
+use iced::widget::svg::{ Appearance, StyleSheet, };
+
+use std::sync::Arc;
+
+
+
+#[derive(Clone, Debug)]
+pub struct Svg {
+    /// Tint color. The svg keeps its own colors if not set.
+    pub color: Option<Arc<Color>>,
+}
+
+impl Svg {
+    /// Attempts to create a theme from its serialized version.
+    pub(crate) fn create(serial: &serial::Svg, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        match serial {
+            serial::Svg::Color(key) => match theme.getcolor(key, "color", &mut errors) {
+                Some(color) => Ok( Svg { color: Some(color) } ),
+                _ => Err(errors),
+            },
+
+            serial::Svg::None => Ok( Svg { color: None } ),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Svg {
+        match &self.color {
+            Some(color) => serial::Svg::Color( export.color(color) ),
+            _ => serial::Svg::None,
+        }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Svg { color: self.color.as_ref().map(|color| remap.color(color)) }
+    }
+}
+
+impl From<Svg> for iced::theme::Svg {
+    fn from(svg: Svg) -> Self {
+        iced::theme::Svg::Custom( Box::new(svg) )
+    }
+}
+
+impl StyleSheet for Svg {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _: &Self::Style) -> Appearance {
+        Appearance { color: self.color.as_ref().map(|c| (**c).into()) }
+    }
+}
@@ -0,0 +1,18 @@
+//! `serde` compatible version of the svg theme.
+
+
+
+use crate::serial::ColorRef;
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Svg {
+    /// Reference to the tint color.
+    Color(ColorRef),
+
+    /// The svg is not tinted.
+    None,
+}
@@ -0,0 +1,1236 @@
+//! Tests of the theme parsing and exporting, using `ron` as the format.
+
+
+
+use crate::{
+    Color,
+    color::{ Derived, Notation, serial::Color as SerialColor },
+    error::{ ErrorKind, ParseError },
+    theme::{ Theme, serial::Theme as Serial },
+};
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::Arc,
+};
+
+
+
+/// Theme exercising every section, inheritance and fallback.
+const THEME: &str = r##"(
+    name: "test",
+    description: "Test theme",
+
+    application: {
+        "default": (background: "black", text: "white"),
+    },
+
+    color: {
+        "black": (0, 0, 0, 1.0),
+        "white": (255, 255, 255, 1.0),
+        "accent": Color(40, 120, 220, 1.0),
+        "transparent": "transparent",
+        "muted": "#808080",
+        "accent-hover": "lighten(accent, 0.1)",
+        "accent-pressed": "darken('accent-hover', 0.2)",
+        "overlay": "alpha(black, 0.5)",
+        "grey": "mix(black, white, 0.5)",
+    },
+
+    border: {
+        "thin": (color: "white", radius: 2.0, width: 1.0),
+        "none": (color: "transparent", radius: 0.0, width: 0.0),
+        "tab": (color: "white", radius: [4.0, 4.0, 0, 0], width: 1.0),
+    },
+
+    gradient: {
+        "sunset": (angle: 90.0, stops: [(0.0, "accent"), (1.0, Color("#ff8000"))]),
+    },
+
+    button: {
+        "primary": (
+            active: Defined((background: "accent", text: "white", border: "thin")),
+            hovered: Defined((background: "accent-hover", text: "black", border: "thin", shadow: (0.0, 1.0))),
+            pressed: None,
+            disabled: Inherited("secondary"),
+        ),
+        "secondary": (
+            active: Defined((background: "black", text: "white", border: "none")),
+            hovered: None,
+            pressed: None,
+            disabled: None,
+        ),
+        "tertiary": (
+            active: Inherited("primary"),
+            hovered: Inherited("primary"),
+            pressed: Inherited("secondary"),
+            disabled: None,
+        ),
+    },
+
+    checkbox: {
+        "default": (
+            active: Defined((background: "black", checkmark: "accent", textcolor: Some("white"), border: "thin")),
+            hovered: None,
+            uactive: Defined((background: "black", checkmark: "accent", textcolor: None, border: "none")),
+            uhovered: None,
+        ),
+    },
+
+    container: {
+        "panel": (color: "black", border: "thin"),
+        "inline": (color: Color(10, 20, 30, 1.0), border: (color: Color("#ff0000"), radius: 4.0, width: 2.0)),
+        "literal": (color: Color("rebeccapurple"), border: (color: Color("alpha(accent, 0.5)"), radius: 0.0, width: 1.0)),
+        "header": (color: "sunset", border: "none"),
+    },
+
+    panegrid: {
+        "default": (
+            region: Defined((background: "accent", border: "thin")),
+            picked: Defined((color: "accent", width: 2.0)),
+            hovered: None,
+        ),
+    },
+
+    picklist: {
+        "default": (
+            active: Defined((background: "black", text: "white", placeholder: "white", border: "thin", handle: "accent")),
+            hovered: None,
+            menu: Defined((background: "black", text: "white", border: "thin", sbackground: "accent", stext: "black")),
+        ),
+        "child": (
+            active: Inherited("default"),
+            hovered: Inherited("default"),
+            menu: Inherited("default"),
+        ),
+    },
+
+    progressbar: {
+        "default": (background: "black", bar: "accent", radius: [2.0, 0.0, 0.0, 2.0]),
+    },
+
+    radio: {
+        "default": (
+            active: Defined((background: "black", dotcolor: "accent", textcolor: None, border: "thin")),
+            hovered: None,
+            uactive: None,
+            uhovered: None,
+        ),
+    },
+
+    rule: {
+        "default": (color: "white", fillmode: Percent(80.0), radius: 0.0, width: 1),
+    },
+
+    scrollable: {
+        "default": (
+            active: Defined((color: "black", border: "none", scolor: "accent", sborder: "thin")),
+            hovered: None,
+            dragging: None,
+        ),
+    },
+
+    slider: {
+        "default": (
+            active: Defined((colors: ("accent", "white"), rwidth: 4.0, rradius: 2.0, hborder: "thin", hcolor: "white", shape: Circle(6.0))),
+            hovered: None,
+            dragging: None,
+        ),
+    },
+
+    svg: {
+        "icon": Color("accent"),
+        "plain": None,
+    },
+
+    text: {
+        "heading": Color("accent"),
+        "body": None,
+    },
+
+    textinput: {
+        "default": (
+            active: Defined((background: "black", border: "thin", icon: "white")),
+            hovered: None,
+            focused: None,
+            disabled: None,
+            placeholder: "white",
+            value: "white",
+            selection: "accent",
+            disabledc: "white",
+        ),
+    },
+
+    toggler: {
+        "default": (
+            active: Defined((background: "accent", bborder: None, foreground: "white", fborder: Some("black"))),
+            hovered: None,
+            uactive: Defined((background: "black", bborder: None, foreground: "white", fborder: None)),
+            uhovered: None,
+        ),
+    },
+
+    tooltip: {
+        "default": (background: "black", text: "white", border: "thin"),
+    },
+)"##;
+
+
+
+/// Parses a theme, panicking on any error.
+fn parse(serial: &Serial) -> Theme {
+    let mut theme = Theme::new();
+    theme.parse(serial).expect("theme should parse without errors");
+    theme
+}
+
+/// Returns the entries of a theme map as sorted debug strings.
+fn dump<T: Debug>(map: &HashMap<String, Arc<T>>) -> Vec<(String, String)> {
+    let mut entries: Vec<_> = map.iter().map(|(k, v)| (k.clone(), format!("{:?}", v))).collect();
+    entries.sort();
+    entries
+}
+
+/// Asserts that two resolved themes contain the same styles.
+fn equivalent(a: &Theme, b: &Theme) {
+    assert_eq!(a.name, b.name);
+    assert_eq!(a.description, b.description);
+
+    assert_eq!(dump(&a.application), dump(&b.application));
+    assert_eq!(dump(&a.border), dump(&b.border));
+    assert_eq!(dump(&a.button), dump(&b.button));
+    assert_eq!(dump(&a.checkbox), dump(&b.checkbox));
+    assert_eq!(dump(&a.color), dump(&b.color));
+    assert_eq!(dump(&a.container), dump(&b.container));
+    assert_eq!(dump(&a.gradient), dump(&b.gradient));
+    assert_eq!(dump(&a.panegrid), dump(&b.panegrid));
+    assert_eq!(dump(&a.picklist), dump(&b.picklist));
+    assert_eq!(dump(&a.progressbar), dump(&b.progressbar));
+    assert_eq!(dump(&a.radio), dump(&b.radio));
+    assert_eq!(dump(&a.rule), dump(&b.rule));
+    assert_eq!(dump(&a.scrollable), dump(&b.scrollable));
+    assert_eq!(dump(&a.slider), dump(&b.slider));
+    assert_eq!(dump(&a.svg), dump(&b.svg));
+    assert_eq!(dump(&a.text), dump(&b.text));
+    assert_eq!(dump(&a.textinput), dump(&b.textinput));
+    assert_eq!(dump(&a.toggler), dump(&b.toggler));
+    assert_eq!(dump(&a.tooltip), dump(&b.tooltip));
+}
+
+
+
+#[test]
+fn parse_all_sections() {
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+
+    assert_eq!(theme.button.len(), 3);
+    assert_eq!(theme.picklist.len(), 2);
+
+    // `pressed: None` falls back on the active state.
+    let primary = &theme.button["primary"];
+    assert!( Arc::ptr_eq(&primary.state[0], &primary.state[2]) );
+
+    // Inherited states are shared with their parent.
+    let secondary = &theme.button["secondary"];
+    assert!( Arc::ptr_eq(&primary.state[3], &secondary.state[3]) );
+}
+
+#[test]
+fn parse_reports_located_errors() {
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.color.remove("accent");
+
+    let mut theme = Theme::new();
+    let errors = theme.parse(&serial).unwrap_err();
+
+    let message = "button `primary`.active.background: color `accent` not found";
+    assert!( errors.iter().any(|e| e.to_string() == message) );
+
+    // Styles that inherit from the failed style report the failed inheritance.
+    let message = "button `tertiary`.active: inherited entry `primary` failed to parse";
+    assert!( errors.iter().any(|e| e.to_string() == message) );
+
+    assert!( !theme.button.contains_key("primary") );
+    assert!( !theme.button.contains_key("tertiary") );
+    assert!( theme.button.contains_key("secondary") );
+
+    // Errors of a whole section have no entry nor field.
+    assert_eq!( ParseError::new("", ErrorKind::NoDefinedState).locate("meta", "").to_string(), "meta: no state is defined" );
+}
+
+#[test]
+fn parse_reports_cycles() {
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.button.insert( "x".into(), ron::from_str(r#"(active: Inherited("y"), hovered: None, pressed: None, disabled: None)"#).unwrap() );
+    serial.button.insert( "y".into(), ron::from_str(r#"(active: Inherited("x"), hovered: None, pressed: None, disabled: None)"#).unwrap() );
+
+    let mut theme = Theme::new();
+    let errors = theme.parse(&serial).unwrap_err();
+
+    let cycle = errors.iter().find_map(|e| match &e.kind {
+        ErrorKind::CyclicInheritance(path) => Some(path.clone()),
+        _ => None,
+    });
+
+    assert_eq!( cycle.unwrap().join(" -> "), "x.active -> y.active -> x.active" );
+    assert!( !theme.button.contains_key("x") );
+    assert!( theme.button.contains_key("primary") );
+}
+
+/// Checks how the `active`, `hovered`, `uactive` and `uhovered` states of a section fall back on each other.
+/// `active` and `hovered` fall back on the first defined state, `uactive` on `active` and `uhovered` on `uactive`.
+/// `state` is the serialized state of every defined state, and `states` gets the resolved states of an entry.
+fn toggle_states<C: serde::de::DeserializeOwned, T>(section: fn(&mut Serial) -> &mut HashMap<String, C>, state: &str, states: fn(&Theme, &str) -> [Arc<T>; 4]) {
+    // Defined states of each entry, and the state each of its states resolves to.
+    let cases = [
+        ([true, false, false, false], [0, 0, 0, 0]),
+        ([true, false, true, false], [0, 0, 2, 2]),
+        ([true, true, false, true], [0, 1, 0, 3]),
+        ([false, false, true, false], [2, 2, 2, 2]),
+    ];
+
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+
+    for (n, (defined, _)) in cases.iter().enumerate() {
+        let fields: Vec<String> = ["active", "hovered", "uactive", "uhovered"].iter().zip(defined)
+            .map(|(field, defined)| match defined {
+                true => format!("{}: Defined({})", field, state),
+                false => format!("{}: None", field),
+            })
+            .collect();
+
+        section(&mut serial).insert( format!("case{}", n), ron::from_str( &format!("({})", fields.join(", ")) ).unwrap() );
+    }
+
+    let theme = parse(&serial);
+
+    for (n, (_, expected)) in cases.iter().enumerate() {
+        let resolved = states(&theme, &format!("case{}", n));
+
+        for a in 0..4 {
+            for b in 0..4 {
+                assert_eq!( Arc::ptr_eq(&resolved[a], &resolved[b]), expected[a] == expected[b], "case{}: states {} and {}", n, a, b );
+            }
+        }
+    }
+}
+
+#[test]
+fn checkbox_states() {
+    use iced::widget::checkbox::StyleSheet;
+
+    toggle_states( |serial| &mut serial.checkbox, r#"(background: "black", checkmark: "accent", textcolor: None, border: "thin")"#, |theme, name| theme.checkbox[name].state.clone() );
+
+    // Unchecked checkboxes are drawn with the unchecked states.
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+    let (checkbox, style) = (&theme.checkbox["default"], iced::Theme::Dark);
+
+    assert_eq!( [checkbox.active(&style, true).border_width, checkbox.hovered(&style, true).border_width], [1.0, 1.0] );
+    assert_eq!( [checkbox.active(&style, false).border_width, checkbox.hovered(&style, false).border_width], [0.0, 0.0] );
+}
+
+#[test]
+fn radio_states() {
+    use iced::widget::radio::StyleSheet;
+
+    toggle_states( |serial| &mut serial.radio, r#"(background: "black", dotcolor: "accent", textcolor: None, border: "thin")"#, |theme, name| theme.radio[name].state.clone() );
+
+    // Unselected radios are drawn with the unselected states.
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.radio.insert( "unselected".into(), ron::from_str(r#"(
+        active: Defined((background: "black", dotcolor: "accent", textcolor: None, border: "thin")),
+        hovered: None,
+        uactive: Defined((background: "black", dotcolor: "accent", textcolor: None, border: "none")),
+        uhovered: None,
+    )"#).unwrap() );
+
+    let theme = parse(&serial);
+    let (radio, style) = (&theme.radio["unselected"], iced::Theme::Dark);
+
+    assert_eq!( [radio.active(&style, true).border_width, radio.hovered(&style, true).border_width], [1.0, 1.0] );
+    assert_eq!( [radio.active(&style, false).border_width, radio.hovered(&style, false).border_width], [0.0, 0.0] );
+}
+
+#[test]
+fn toggler_states() {
+    use iced::widget::toggler::StyleSheet;
+
+    toggle_states( |serial| &mut serial.toggler, r#"(background: "accent", bborder: None, foreground: "white", fborder: None)"#, |theme, name| theme.toggler[name].state.clone() );
+
+    // Untoggled togglers are drawn with the untoggled states.
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+    let (toggler, style) = (&theme.toggler["default"], iced::Theme::Dark);
+
+    assert_eq!( [toggler.active(&style, true).foreground_border, toggler.hovered(&style, true).foreground_border], [Some(iced::Color::BLACK); 2] );
+    assert_eq!( [toggler.active(&style, false).foreground_border, toggler.hovered(&style, false).foreground_border], [None; 2] );
+}
+
+#[test]
+fn apptheme_lookup() {
+    use crate::{ AppTheme, Style };
+    use iced::widget::{ button, checkbox, container, text };
+
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.tooltip.insert( "panel".into(), ron::from_str(r#"(background: "accent", text: "white", border: "thin")"#).unwrap() );
+
+    let mut app = AppTheme::new( parse(&serial) );
+    app.defaults.button = "secondary".into();
+    app.fallback = iced::Theme::Light;
+
+    let accent = iced::Color::from( Color::new(40, 120, 220, 1.0) );
+    let background = |color| Some( iced::Background::Color(color) );
+
+    // The style key is looked up first, then the default key of the widget.
+    assert_eq!( button::StyleSheet::active(&app, &Style::new("primary")).background, background(accent) );
+    assert_eq!( button::StyleSheet::active(&app, &Style::new("missing")).background, background(iced::Color::BLACK) );
+    assert_eq!( checkbox::StyleSheet::active(&app, &Style::new("missing"), true).border_width, 1.0 );
+
+    // The fallback theme is used when neither key exists.
+    app.defaults.button = "missing".into();
+
+    let fallback = button::StyleSheet::active(&iced::Theme::Light, &iced::theme::Button::default());
+    assert_eq!( button::StyleSheet::active(&app, &Style::new("missing")).background, fallback.background );
+    assert_eq!( text::StyleSheet::appearance(&app, Style::new("missing")).color, None );
+    assert_eq!( text::StyleSheet::appearance(&app, Style::new("heading")).color, Some(accent) );
+
+    // Containers are looked up before tooltips with the same key.
+    assert_eq!( container::StyleSheet::appearance(&app, &Style::new("panel")).background, background(iced::Color::BLACK) );
+
+    // A key missing from the containers is looked up in the tooltips.
+    let tooltip = container::StyleSheet::appearance(&app, &Style::new("default"));
+    assert_eq!( tooltip.background, background(iced::Color::BLACK) );
+    assert_eq!( tooltip.border_width, 1.0 );
+
+    // The default container key does not fall back on the tooltips.
+    let fallback = container::StyleSheet::appearance(&iced::Theme::Light, &iced::theme::Container::default());
+    assert_eq!( container::StyleSheet::appearance(&app, &Style::new("missing")).background, fallback.background );
+}
+
+#[test]
+fn serial_round_trip() {
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+
+    // Export through the serialized format, as a theme editor would.
+    let string = ron::to_string( &theme.to_serial() ).unwrap();
+    let again = parse( &ron::from_str(&string).unwrap() );
+
+    equivalent(&theme, &again);
+}
+
+#[test]
+fn serial_mutual_inheritance() {
+    // Entries can inherit from each other through different states.
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.button.insert( "x".into(), ron::from_str(r#"(active: Defined((background: "black", text: "white", border: "none")), hovered: Inherited("y"), pressed: None, disabled: None)"#).unwrap() );
+    serial.button.insert( "y".into(), ron::from_str(r#"(active: Inherited("x"), hovered: Defined((background: "accent", text: "white", border: "thin")), pressed: None, disabled: None)"#).unwrap() );
+    let theme = parse(&serial);
+
+    let string = ron::to_string( &theme.to_serial() ).unwrap();
+    let again = parse( &ron::from_str(&string).unwrap() );
+
+    equivalent(&theme, &again);
+    assert!( format!("{:?}", again.to_serial().button["x"]).contains(r#"Inherited("y")"#) );
+}
+
+#[test]
+fn serial_keeps_color_changes() {
+    let mut theme = parse( &ron::from_str(THEME).unwrap() );
+    theme.color.insert( "accent".into(), Arc::new( Color::new(200, 40, 40, 1.0) ) );
+
+    let again = parse( &theme.to_serial() );
+
+    assert_eq!( *again.color["accent"], Color::new(200, 40, 40, 1.0) );
+    assert_eq!( **again.button["primary"].state[0].background.color().unwrap(), Color::new(200, 40, 40, 1.0) );
+
+    // Keys and structure are kept.
+    let serial = again.to_serial();
+    assert!( format!("{:?}", serial.button["tertiary"]).contains(r#"Inherited("primary")"#) );
+}
+
+#[test]
+fn serial_keeps_style_changes() {
+    use crate::{ Background, Border, Container, Gradient, serial::Ref };
+
+    let mut theme = parse( &ron::from_str(THEME).unwrap() );
+
+    // Edit a border, which the styles reference by key.
+    let thin = Border { width: 3.0, ..(*theme.border["thin"]).clone() };
+    theme.border.insert( "thin".into(), Arc::new(thin) );
+
+    // Add a container, with a gradient that is not in the gradient table.
+    let gradient = Gradient { angle: 45.0, stops: vec![ (0.0, theme.color["black"].clone()), (1.0, Arc::new( Color::new(1, 2, 3, 1.0) )) ] };
+    let card = Container { color: Background::Gradient( Arc::new(gradient) ), border: theme.border["tab"].clone() };
+    theme.container.insert( "card".into(), Arc::new(card) );
+
+    // Remove a button, which the other buttons do not inherit from.
+    theme.button.remove("tertiary");
+
+    let serial = theme.to_serial();
+    assert_eq!( serial.border["thin"].width, 3.0 );
+    assert!( matches!( &serial.container["card"].border, Ref::Key(key) if key == "tab" ) );
+    assert!( !serial.button.contains_key("tertiary") );
+
+    let again = parse(&serial);
+    assert_eq!( again.border["thin"].width, 3.0 );
+    assert_eq!( again.container["panel"].border.width, 3.0 );
+    assert_eq!( format!("{:?}", again.container["card"]), format!("{:?}", theme.container["card"]) );
+
+    // Styles corrected by `fix_contrast` are exported, and so are the styles inheriting from them.
+    let fixed = again.fix_contrast( crate::accessibility::Level::AA.ratio(), &Color::BLACK );
+    let exported = parse( &fixed.to_serial() );
+
+    for name in ["primary", "secondary"] {
+        assert_eq!( format!("{:?}", exported.button[name]), format!("{:?}", fixed.button[name]) );
+    }
+
+    assert_eq!( format!("{:?}", exported.picklist["child"]), format!("{:?}", fixed.picklist["child"]) );
+}
+
+
+
+/// Deserializes a color from a RON string.
+fn color(string: &str) -> iced::Color {
+    ron::from_str::<Color>(string).unwrap().into()
+}
+
+#[test]
+fn color_notations() {
+    let orange = iced::Color::from_rgba8(255, 136, 0, 1.0);
+
+    assert_eq!( color("Color(255, 136, 0, 1.0)"), orange );
+    assert_eq!( color("(255, 136, 0, 1.0)"), orange );
+    assert_eq!( color("\"#ff8800\""), orange );
+    assert_eq!( color("\"#F80\""), orange );
+    assert_eq!( color(r#""hsl(32, 100%, 50%)""#), orange );
+    assert_eq!( color(r#""hsl(32deg 100% 50%)""#), orange );
+    assert_eq!( color("Rgba(r: 1.0, g: 0.53333336, b: 0.0)"), orange );
+
+    assert_eq!( color("\"#ff880080\""), iced::Color::from_rgba8(255, 136, 0, 128.0 / 255.0) );
+    assert_eq!( color(r#""hsla(32, 100%, 50%, 0.5)""#), iced::Color::from_rgba8(255, 136, 0, 0.5) );
+
+    assert_eq!( color(r#""RebeccaPurple""#), iced::Color::from_rgba8(102, 51, 153, 1.0) );
+    assert_eq!( color(r#""transparent""#), iced::Color::from_rgba8(0, 0, 0, 0.0) );
+
+    for invalid in ["\"#12\"", "\"#gggggg\"", r#""hsl(0, 200%, 50%)""#, r#""hsl(0)""#, r#""notacolor""#, "Rgba(r: 2.0, g: 0.0, b: 0.0)"] {
+        assert!( ron::from_str::<Color>(invalid).is_err(), "{} should not parse", invalid );
+    }
+}
+
+#[test]
+fn color_serialize_notation() {
+    let red = Color::new(255, 0, 0, 1.0);
+    let write = |notation| ron::to_string( &red.with_notation(notation) ).unwrap();
+
+    assert_eq!( write(Notation::Tuple), "(255,0,0,1.0)" );
+    assert_eq!( write(Notation::Hex), "\"#ff0000\"" );
+    assert_eq!( write(Notation::Hsl), r#""hsl(0, 100%, 50%)""# );
+    assert_eq!( write(Notation::Name), r#""red""# );
+    assert_eq!( write(Notation::Rgba), "(r:1.0,g:0.0,b:0.0,a:1.0)" );
+
+    // Colors without a name fall back on hex.
+    assert_eq!( ron::to_string( &Color::new(1, 2, 3, 0.5).with_notation(Notation::Name) ).unwrap(), "\"#01020380\"" );
+
+    // Every notation reads back as the same color.
+    for notation in [Notation::Tuple, Notation::Hex, Notation::Hsl, Notation::Name, Notation::Rgba] {
+        let color = Color::new(100, 149, 237, 1.0).with_notation(notation);
+        let again: Color = ron::from_str( &ron::to_string(&color).unwrap() ).unwrap();
+
+        assert_eq!( iced::Color::from(again), iced::Color::from(color) );
+        assert_eq!( again.notation(), notation );
+    }
+}
+
+#[test]
+fn serial_keeps_notation() {
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+
+    let mut serial = theme.to_serial();
+    assert!( matches!( serial.color["muted"], SerialColor::Value(c) if c.notation() == Notation::Hex ) );
+
+    serial.set_notation(Notation::Hsl);
+    let string = ron::to_string(&serial).unwrap();
+
+    assert!( string.contains(r#""hsl(0, 0%, 50.2%)""#) );
+    equivalent( &theme, &parse( &ron::from_str(&string).unwrap() ) );
+}
+
+
+
+#[test]
+fn derived_colors() {
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+
+    let get = |key: &str| iced::Color::from( *theme.color[key] );
+
+    assert_eq!( get("grey"), iced::Color::from_rgba8(128, 128, 128, 1.0) );
+    assert_eq!( get("overlay"), iced::Color::from_rgba8(0, 0, 0, 0.5) );
+    assert_eq!( get("accent-hover"), Color::new(40, 120, 220, 1.0).lighten(0.1).into() );
+    assert_eq!( get("accent-pressed"), Color::new(40, 120, 220, 1.0).lighten(0.1).darken(0.2).into() );
+
+    // Styles reference derived colors as any other color.
+    assert!( Arc::ptr_eq( theme.button["primary"].state[1].background.color().unwrap(), &theme.color["accent-hover"] ) );
+}
+
+#[test]
+fn derived_color_errors() {
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+
+    for (key, expression) in [("a", "lighten(b, 0.1)"), ("b", "mix(a, white, 0.1)"), ("c", "lighten(a, 0.1)"), ("d", "darken(nothing, 0.1)")] {
+        serial.color.insert( key.into(), SerialColor::Derived( expression.parse().unwrap() ) );
+    }
+
+    let mut theme = Theme::new();
+    let errors: Vec<String> = theme.parse(&serial).unwrap_err().iter().map(|e| e.to_string()).collect();
+
+    assert_eq!( errors.len(), 2, "{:?}", errors );
+    assert!( errors.contains( &"color `a`: cyclic color derivation a -> b -> a".to_string() ) );
+    assert!( errors.contains( &"color `d`: color `nothing` not found".to_string() ) );
+
+    // Colors that depend on failed colors fail silently.
+    assert!( !theme.color.contains_key("c") );
+    assert!( theme.color.contains_key("accent-pressed") );
+
+    for invalid in [r#""lighten(accent)""#, r#""mix(a, b, 2.0)""#, r#""alpha(, 0.5)""#] {
+        assert!( ron::from_str::<SerialColor>(invalid).is_err(), "{} should not parse", invalid );
+    }
+}
+
+#[test]
+fn serial_keeps_derived_colors() {
+    let mut theme = parse( &ron::from_str(THEME).unwrap() );
+
+    // Editing a base color updates the colors derived from it.
+    theme.color.insert( "accent".into(), Arc::new( Color::new(200, 40, 40, 1.0) ) );
+
+    let serial = theme.to_serial();
+    assert!( matches!( &serial.color["accent-hover"], SerialColor::Derived( Derived::Lighten(key, _) ) if key == "accent" ) );
+
+    let string = ron::to_string(&serial).unwrap();
+    assert!( string.contains(r#""lighten(accent, 0.1)""#) );
+
+    let mut again = parse( &ron::from_str(&string).unwrap() );
+    assert_eq!( iced::Color::from( *again.color["accent-hover"] ), Color::new(200, 40, 40, 1.0).lighten(0.1).into() );
+
+    // Replacing a derived color writes its value instead.
+    again.color.insert( "accent-hover".into(), Arc::new( Color::WHITE ) );
+    assert!( matches!( again.to_serial().color["accent-hover"], SerialColor::Value(_) ) );
+}
+
+
+
+#[test]
+fn inline_values() {
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+
+    let inline = &theme.container["inline"];
+    assert_eq!( iced::Color::from(**inline.color.color().unwrap()), iced::Color::from_rgba8(10, 20, 30, 1.0) );
+    assert_eq!( iced::Color::from(*inline.border.color), iced::Color::from_rgba8(255, 0, 0, 1.0) );
+    assert_eq!( inline.border.width, 2.0 );
+
+    // Wrapped strings are parsed as colors or derived colors.
+    let literal = &theme.container["literal"];
+    assert_eq!( iced::Color::from(**literal.color.color().unwrap()), iced::Color::from_rgba8(102, 51, 153, 1.0) );
+    assert_eq!( iced::Color::from(*literal.border.color), iced::Color::from_rgba8(40, 120, 220, 0.5) );
+
+    // Inline values do not pollute the color and border maps.
+    assert_eq!( theme.border.len(), 3 );
+    assert!( !theme.color.contains_key("#ff0000") );
+
+    // Wrapped strings are kept when exported.
+    let string = ron::to_string( &theme.to_serial() ).unwrap();
+    assert!( string.contains(r#"("rebeccapurple")"#) && string.contains(r#"("alpha(accent, 0.5)")"#) );
+    equivalent( &theme, &parse( &ron::from_str(&string).unwrap() ) );
+}
+
+#[test]
+fn inline_value_errors() {
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.container.insert( "bad".into(), ron::from_str(r#"(color: "notakey", border: (color: Color("lighten(nothing, 0.1)"), radius: 0.0, width: 1.0))"#).unwrap() );
+
+    let mut theme = Theme::new();
+    let errors: Vec<String> = theme.parse(&serial).unwrap_err().iter().map(|e| e.to_string()).collect();
+
+    assert_eq!( errors, [
+        "container `bad`.color: color `notakey` not found",
+        "container `bad`.border.color: color `nothing` not found",
+    ] );
+
+    // Strings are always keys, so a misspelled key is not read as a color name.
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.container.insert( "typo".into(), ron::from_str(r#"(color: "blakc", border: (color: "lightblue", radius: 0.0, width: 1.0))"#).unwrap() );
+
+    let errors: Vec<String> = Theme::new().parse(&serial).unwrap_err().iter().map(|e| e.to_string()).collect();
+
+    assert_eq!( errors, [
+        "container `typo`.color: color `blakc` not found",
+        "container `typo`.border.color: color `lightblue` not found",
+    ] );
+}
+
+
+
+#[test]
+fn corner_radius() {
+    use crate::serial::Radius;
+
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+
+    assert_eq!( <[f32; 4]>::from( theme.border["thin"].radius ), [2.0; 4] );
+    assert_eq!( <[f32; 4]>::from( theme.border["tab"].radius ), [4.0, 4.0, 0.0, 0.0] );
+    assert_eq!( <[f32; 4]>::from( theme.progressbar["default"].radius ), [2.0, 0.0, 0.0, 2.0] );
+
+    assert_eq!( ron::to_string( &Radius::Uniform(2.0) ).unwrap(), "2.0" );
+    assert_eq!( ron::to_string( &Radius::Corners([1.0, 2.0, 3.0, 4.0]) ).unwrap(), "(1.0,2.0,3.0,4.0)" );
+    assert_eq!( ron::from_str::<Radius>("(1.0,2.0,3.0,4.0)").unwrap(), Radius::Corners([1.0, 2.0, 3.0, 4.0]) );
+
+    for invalid in ["[1.0, 2.0, 3.0]", "[1.0, 2.0, 3.0, 4.0, 5.0]", r#""round""#] {
+        assert!( ron::from_str::<Radius>(invalid).is_err(), "{} should not parse", invalid );
+    }
+}
+
+
+
+#[test]
+fn button_shadow() {
+    use iced::widget::button::StyleSheet;
+
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+    let primary = &theme.button["primary"];
+
+    assert_eq!( primary.hovered(&iced::Theme::Dark).shadow_offset, iced::Vector::new(0.0, 1.0) );
+
+    // The shadow is optional.
+    assert_eq!( primary.active(&iced::Theme::Dark).shadow_offset, iced::Vector::new(0.0, 0.0) );
+}
+
+
+
+#[test]
+fn gradient_backgrounds() {
+    use iced::widget::container::StyleSheet;
+
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+
+    let sunset = &theme.gradient["sunset"];
+    assert_eq!( sunset.stops.len(), 2 );
+    assert_eq!( iced::Color::from(*sunset.stops[1].1), iced::Color::from_rgba8(255, 128, 0, 1.0) );
+
+    let expected = iced::Background::Gradient( sunset.as_ref().into() );
+    assert_eq!( theme.container["header"].appearance(&iced::Theme::Dark).background, Some(expected) );
+
+    // Color keys and literals are still colors.
+    assert!( theme.container["panel"].color.color().is_some() );
+    assert!( theme.container["literal"].color.color().is_some() );
+    assert!( theme.container["header"].color.color().is_none() );
+}
+
+#[test]
+fn gradient_errors() {
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.gradient.insert( "empty".into(), ron::from_str("(angle: 0.0, stops: [])").unwrap() );
+    serial.gradient.insert( "offset".into(), ron::from_str(r#"(angle: 0.0, stops: [(0.0, "black"), (1.5, "nothing")])"#).unwrap() );
+    serial.container.insert( "bad".into(), ron::from_str(r#"(color: "noise", border: "none")"#).unwrap() );
+
+    let mut theme = Theme::new();
+    let mut errors: Vec<String> = theme.parse(&serial).unwrap_err().iter().map(|e| e.to_string()).collect();
+    errors.sort();
+
+    assert_eq!( errors, [
+        "container `bad`.color: color `noise` not found",
+        "gradient `empty`.stops: invalid gradient: expected 1 to 8 stops, found 0",
+        "gradient `offset`.stops.1: color `nothing` not found",
+        "gradient `offset`.stops.1: invalid gradient: offset 1.5 is outside of [0, 1]",
+    ] );
+}
+
+
+
+#[test]
+fn contrast_report() {
+    use crate::accessibility::{ Level, Report };
+
+    assert!( (Color::BLACK.contrast(&Color::WHITE) - 21.0).abs() < 1e-3 );
+    assert_eq!( Color::WHITE.contrast(&Color::WHITE), 1.0 );
+
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+    let report = Report::new(&theme, &Color::BLACK);
+
+    let failing = |level| report.failing(level).map(|v| format!("{} `{}`.{}", v.section, v.entry, v.field)).collect::<Vec<_>>();
+
+    assert_eq!( failing(Level::AA), [
+        "button `primary`.active.text",
+        "button `primary`.pressed.text",
+        "button `tertiary`.active.text",
+        "button `tertiary`.disabled.text",
+    ] );
+
+    assert_eq!( failing(Level::AAA).len(), 8 );
+    assert!( failing(Level::AAA).contains( &"picklist `default`.menu.stext".to_string() ) );
+    assert!( !report.passes(Level::AA) );
+
+    // Translucent colors are blended before computing the contrast.
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.tooltip.insert( "faded".into(), ron::from_str(r#"(background: "black", text: Color("alpha(white, 0.3)"), border: "none")"#).unwrap() );
+    serial.tooltip.insert( "glass".into(), ron::from_str(r#"(background: Color("alpha(white, 0.0)"), text: "black", border: "none")"#).unwrap() );
+
+    let report = Report::new( &parse(&serial), &Color::BLACK );
+    let faded = report.violations.iter().find(|v| v.entry == "faded").unwrap();
+    let glass = report.violations.iter().find(|v| v.entry == "glass").unwrap();
+
+    assert_eq!( iced::Color::from(faded.foreground), iced::Color::from_rgba8(77, 77, 77, 1.0) );
+    assert_eq!( faded.level, Level::AA );
+    assert_eq!( glass.ratio, 1.0 );
+}
+
+#[test]
+fn fix_contrast() {
+    use crate::accessibility::{ Level, Report };
+
+    // OKLCH conversion round trips.
+    for color in [Color::new(40, 120, 220, 1.0), Color::new(255, 128, 0, 1.0), Color::WHITE, Color::BLACK] {
+        let (l, c, h) = color.to_oklch();
+        assert_eq!( Color::from_oklch(l, c, h, 1.0), color );
+    }
+
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+    let fixed = theme.fix_contrast( Level::AA.ratio(), &Color::BLACK );
+
+    let report = Report::new(&fixed, &Color::BLACK);
+    assert!( report.passes(Level::AA), "{}", report );
+
+    // The original theme is left alone.
+    assert!( !Report::new(&theme, &Color::BLACK).passes(Level::AA) );
+    assert!( Arc::ptr_eq( &theme.button["primary"].state[0].text, &theme.color["white"] ) );
+
+    // Only the lightness of the offending colors changes, and passing colors are kept.
+    let text = &fixed.button["primary"].state[0].text;
+    assert!( (text.to_oklch().0 - Color::WHITE.to_oklch().0).abs() > 0.01 );
+    assert!( Arc::ptr_eq( &fixed.tooltip["default"].text, &theme.color["white"] ) );
+
+    // A theme that already passes is left as it is, and exports the same structure.
+    let serial = |theme: &Theme| ron::from_str::<ron::Value>( &ron::to_string( &theme.to_serial() ).unwrap() ).unwrap();
+    let same = theme.fix_contrast( 1.0, &Color::BLACK );
+
+    assert!( theme.button.iter().all(|(name, button)| Arc::ptr_eq( button, &same.button[name] )) );
+    assert_eq!( serial(&same), serial(&theme) );
+    assert_eq!( serial(&fixed.fix_contrast( Level::AA.ratio(), &Color::BLACK )), serial(&fixed) );
+
+    // Unreachable targets get the closest contrast.
+    let fixed = theme.fix_contrast( Level::AAA.ratio(), &Color::BLACK );
+    assert_eq!( *fixed.button["primary"].state[0].text, Color::BLACK );
+
+    // Translucent backgrounds are blended over the given backdrop, as in the report.
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.tooltip.insert( "glass".into(), ron::from_str(r#"(background: Color("alpha(accent, 0.2)"), text: "white", border: "none")"#).unwrap() );
+    let theme = parse(&serial);
+
+    for backdrop in [Color::BLACK, Color::WHITE] {
+        let report = Report::new( &theme.fix_contrast( Level::AA.ratio(), &backdrop ), &backdrop );
+        assert!( report.passes(Level::AA), "{}", report );
+    }
+}
+
+#[test]
+fn vision_filters() {
+    use crate::vision::{ Deficiency, Filter };
+
+    let protanopia = Filter::Simulate(Deficiency::Protanopia);
+
+    // Greys are seen the same, while red and green get closer.
+    assert_eq!( protanopia.apply(&Color::WHITE), Color::WHITE );
+    assert_eq!( protanopia.apply(&"red".parse::<Color>().unwrap()), Color::new(109, 95, 0, 1.0) );
+
+    // Daltonized red and green are easier to tell apart for protanopes.
+    let daltonize = Filter::Daltonize(Deficiency::Protanopia);
+    let (red, green): (Color, Color) = ("red".parse().unwrap(), "green".parse().unwrap());
+    let seen = |c: &Color| protanopia.apply(c);
+
+    assert!( seen(&daltonize.apply(&red)).contrast( &seen(&daltonize.apply(&green)) ) > seen(&red).contrast( &seen(&green) ) );
+    assert_eq!( protanopia.apply(&"#ff000080".parse::<Color>().unwrap()).notation(), Notation::Hex );
+
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+    let filtered = theme.filter(protanopia);
+
+    // Every color of the table is remapped, and the styles reference the remapped colors.
+    for (key, color) in &theme.color {
+        assert_eq!( *filtered.color[key], protanopia.apply(color) );
+    }
+
+    assert!( Arc::ptr_eq( filtered.button["primary"].state[0].background.color().unwrap(), &filtered.color["accent"] ) );
+
+    // Inline and literal colors are remapped too.
+    let inline = filtered.container["inline"].color.color().unwrap();
+    assert_eq!( **inline, protanopia.apply(&Color::new(10, 20, 30, 1.0)) );
+
+    let literal = &filtered.container["literal"].border.color;
+    assert_ne!( literal, &theme.container["literal"].border.color );
+
+    // The original theme is left alone.
+    assert_eq!( *theme.color["accent"], Color::new(40, 120, 220, 1.0) );
+}
+
+#[test]
+fn vision_filters_compose() {
+    use crate::{ accessibility::Level, vision::{ Deficiency, Filter } };
+
+    let protanopia = Filter::Simulate(Deficiency::Protanopia);
+    let twice = |c: &Color| protanopia.apply( &protanopia.apply(c) );
+
+    let theme = parse( &ron::from_str(THEME).unwrap() );
+    let filtered = theme.filter(protanopia).filter(protanopia);
+
+    // Filtering twice applies the filter twice to every color, inline or not.
+    for (key, color) in &theme.color {
+        assert_eq!( *filtered.color[key], twice(color) );
+    }
+
+    assert_eq!( **filtered.container["inline"].color.color().unwrap(), twice(theme.container["inline"].color.color().unwrap()) );
+    assert_eq!( *filtered.container["literal"].border.color, twice(&theme.container["literal"].border.color) );
+
+    // Shared colors, borders and inherited states are still shared.
+    let primary = &filtered.button["primary"];
+    assert!( Arc::ptr_eq( primary.state[1].background.color().unwrap(), &filtered.color["accent-hover"] ) );
+    assert!( Arc::ptr_eq( &primary.state[0].border, &filtered.border["thin"] ) );
+    assert!( Arc::ptr_eq( &primary.state[3], &filtered.button["secondary"].state[3] ) );
+
+    // The filtered theme exports its filtered colors.
+    equivalent( &filtered, &parse( &filtered.to_serial() ) );
+
+    // Colors corrected by `fix_contrast` are filtered, not reverted.
+    let fixed = theme.fix_contrast( Level::AA.ratio(), &Color::BLACK );
+    let filtered = fixed.filter(protanopia);
+
+    assert_ne!( fixed.button["primary"].state[0].text, theme.button["primary"].state[0].text );
+    assert_eq!( *filtered.button["primary"].state[0].text, protanopia.apply(&fixed.button["primary"].state[0].text) );
+    assert_eq!( *filtered.tooltip["default"].text, protanopia.apply(&fixed.tooltip["default"].text) );
+}
+
+/// Creates a package folder with the given files in the temporary folder.
+fn package(name: &str, files: &[(&str, &[u8])]) -> std::path::PathBuf {
+    let root = std::env::temp_dir().join( format!("marcel-{}-{}", name, std::process::id()) );
+    let _ = std::fs::remove_dir_all(&root);
+
+    for (path, bytes) in files {
+        let path = root.join(path);
+        std::fs::create_dir_all( path.parent().unwrap() ).unwrap();
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    root
+}
+
+/// Creates a minimal TrueType font with the given family name and weight class.
+fn font(family: &str, weight: u16) -> Vec<u8> {
+    let be = |values: &[u16]| values.iter().flat_map(|v| v.to_be_bytes()).collect::<Vec<u8>>();
+
+    let name: Vec<u16> = family.encode_utf16().collect();
+
+    let mut os2 = be(&[0, 0, weight, 5]);
+    os2.resize(78, 0);
+
+    let mut head = vec![0; 54];
+    head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+
+    let tables: [(&[u8; 4], Vec<u8>); 5] = [
+        (b"OS/2", os2),
+        (b"head", head),
+        (b"hhea", vec![0; 36]),
+        (b"maxp", be(&[0x0000, 0x5000, 1])),
+        (b"name", [be(&[0, 1, 18, 3, 1, 0x0409, 1, 2 * name.len() as u16, 0]), be(&name)].concat()),
+    ];
+
+    let mut header = [vec![0, 1, 0, 0], be(&[tables.len() as u16, 0, 0, 0])].concat();
+    let mut data = Vec::new();
+    let mut offset = 12 + 16 * tables.len();
+
+    for (tag, mut table) in tables {
+        header.extend_from_slice(tag);
+        header.extend_from_slice(&[0; 4]);
+        header.extend_from_slice(&(offset as u32).to_be_bytes());
+        header.extend_from_slice(&(table.len() as u32).to_be_bytes());
+
+        table.resize((table.len() + 3) & !3, 0);
+        offset += table.len();
+        data.extend(table);
+    }
+
+    [header, data].concat()
+}
+
+#[test]
+fn package_open() {
+    use crate::{ ThemePackage, package::format::Ron };
+
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.image.insert( "save".into(), "img/icons/save.svg".into() );
+    serial.font.insert( "heading".into(), "font/Inter-Bold.ttf".into() );
+    serial.font.insert( "title".into(), "font/Inter-Bold.ttf".into() );
+    let theme = ron::to_string(&serial).unwrap();
+
+    let root = package("open", &[
+        ("theme.ron", theme.as_bytes()),
+        ("theme.meta.ron", META.as_bytes()),
+        ("img/img.meta.ron", br#"{ "icons/save.svg": (alt: Some("Save"), size: Some((16.0, 16.0))) }"#),
+        ("img/background.png", b"png"),
+        ("img/icons/save.svg", b"<svg/>"),
+        ("img/notes.txt", b"ignored"),
+        ("font/Inter-Bold.ttf", &font("Inter", 700)),
+    ]);
+
+    let package = ThemePackage::open::<Ron>(&root).unwrap();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!( package.theme.name, "test" );
+
+    #[cfg(feature = "meta")]
+    assert_eq!( package.meta.unwrap().version, semver::Version::new(1, 2, 0) );
+
+    #[cfg(feature = "image")]
+    {
+        use crate::image::Handle;
+
+        let mut images: Vec<&str> = package.images.keys().map(String::as_str).collect();
+        images.sort();
+
+        assert_eq!( images, ["background.png", "icons/save.svg"] );
+        assert!( matches!(package.images["background.png"].handle, Handle::Raster(_)) );
+        assert!( matches!(package.images["icons/save.svg"].handle, Handle::Vector(_)) );
+        assert_eq!( package.images["background.png"].alt, None );
+        assert_eq!( package.images["icons/save.svg"].alt.as_deref(), Some("Save") );
+        assert_eq!( package.images["icons/save.svg"].size, Some( iced::Size::new(16.0, 16.0) ) );
+        assert!( std::sync::Arc::ptr_eq( &package.theme.image["save"], &package.images["icons/save.svg"] ) );
+    }
+
+    #[cfg(feature = "font")]
+    {
+        assert_eq!( package.fonts["Inter-Bold.ttf"].bytes, font("Inter", 700) );
+
+        let heading = package.theme.font["heading"].descriptor;
+
+        assert_eq!( heading.family, iced::font::Family::Name("Inter") );
+        assert_eq!( heading.weight, iced::font::Weight::Bold );
+        assert_eq!( heading.stretch, iced::font::Stretch::Normal );
+
+        // The family name is only leaked once.
+        let again = crate::Font::from_memory( font("Inter", 400) ).unwrap();
+
+        match (heading.family, again.descriptor.family) {
+            (iced::font::Family::Name(a), iced::font::Family::Name(b)) => assert!( std::ptr::eq(a, b) ),
+            _ => unreachable!(),
+        }
+
+        // The file is loaded once, even if it has two names.
+        assert_eq!( package.theme.load_fonts().actions().len(), 1 );
+    }
+}
+
+#[test]
+fn package_errors() {
+    use crate::{ ThemePackage, package::format::Ron };
+
+    let open = |name: &str, files: &[(&str, &[u8])]| {
+        let root = package(name, files);
+        let error = ThemePackage::open::<Ron>(&root).unwrap_err().to_string();
+        std::fs::remove_dir_all(&root).unwrap();
+        error
+    };
+
+    assert_eq!( open("missing", &[("theme.json", b"{}")]), "`theme.ron`: file not found" );
+
+    #[cfg(feature = "meta")]
+    assert_eq!( open("meta", &[("theme.ron", THEME.as_bytes()), ("theme.meta.ron", br#"(version: "1", variant: Dark)"#)]),
+        "`theme.meta.ron`: invalid metadata\n  meta.version: invalid version `1`: unexpected end of input while parsing major version number" );
+
+    assert!( open("format", &[("theme.ron", b"(name: ")]).starts_with("`theme.ron`: invalid format: ") );
+
+    let mut serial: Serial = ron::from_str(THEME).unwrap();
+    serial.container.insert( "bad".into(), ron::from_str(r#"(color: "noise", border: "none")"#).unwrap() );
+    let theme = ron::to_string(&serial).unwrap();
+
+    assert_eq!( open("parse", &[("theme.ron", theme.as_bytes())]), "`theme.ron`: invalid theme\n  container `bad`.color: color `noise` not found" );
+
+    #[cfg(feature = "image")]
+    {
+        let mut serial: Serial = ron::from_str(THEME).unwrap();
+        serial.image.insert( "logo".into(), "img/logo.png".into() );
+        let theme = ron::to_string(&serial).unwrap();
+
+        assert_eq!( open("image", &[("theme.ron", theme.as_bytes())]), "`theme.ron`: invalid theme\n  image `logo`: image `img/logo.png` not found" );
+    }
+
+    #[cfg(feature = "font")]
+    {
+        let mut serial: Serial = ron::from_str(THEME).unwrap();
+        serial.font.insert( "heading".into(), "font/Inter-Bold.ttf".into() );
+        let theme = ron::to_string(&serial).unwrap();
+
+        assert_eq!( open("font", &[("theme.ron", theme.as_bytes())]), "`theme.ron`: invalid theme\n  font `heading`: font `font/Inter-Bold.ttf` not found" );
+        assert_eq!( open("badfont", &[("theme.ron", THEME.as_bytes()), ("font/bad.ttf", b"ttf")]), "`font/bad.ttf`: invalid font: unknown magic" );
+    }
+
+    // IO errors are located relative to the package.
+    let missing = ThemePackage::open::<Ron>( std::env::temp_dir().join("marcel-nowhere") ).unwrap_err();
+    assert!( missing.file.as_os_str().is_empty() && matches!(missing.kind, crate::error::PackageErrorKind::Io(_)) );
+}
+
+/// Creates a `.zip` archive with the given files.
+#[cfg(feature = "archive")]
+fn zip(files: &[(&str, &[u8])]) -> std::io::Cursor<Vec<u8>> {
+    use std::io::Write;
+
+    let mut writer = zip::ZipWriter::new( std::io::Cursor::new(Vec::new()) );
+
+    for (path, bytes) in files {
+        writer.start_file(*path, zip::write::FileOptions::default()).unwrap();
+        writer.write_all(bytes).unwrap();
+    }
+
+    std::io::Cursor::new( writer.finish().unwrap().into_inner() )
+}
+
+/// Creates a `.tar.gz` archive with the given files.
+/// The paths are written in the header as is, so they can be unsafe.
+#[cfg(feature = "archive")]
+fn tar_gz(files: &[(&str, tar::EntryType, &[u8])]) -> std::io::Cursor<Vec<u8>> {
+    let encoder = flate2::write::GzEncoder::new( Vec::new(), flate2::Compression::default() );
+    let mut builder = tar::Builder::new(encoder);
+
+    for (path, kind, bytes) in files {
+        let mut header = tar::Header::new_gnu();
+        header.as_old_mut().name[..path.len()].copy_from_slice( path.as_bytes() );
+        header.set_entry_type(*kind);
+        header.set_size( bytes.len() as u64 );
+        header.set_mode(0o644);
+        header.set_cksum();
+
+        builder.append(&header, *bytes).unwrap();
+    }
+
+    std::io::Cursor::new( builder.into_inner().unwrap().finish().unwrap() )
+}
+
+#[test]
+#[cfg(feature = "archive")]
+fn package_archives() {
+    use crate::{ ThemePackage, package::{ archive::Limits, format::Ron } };
+
+    let limits = Limits::default();
+
+    // The root of the archive is the root of the package.
+    let archive = zip(&[("theme.ron", THEME.as_bytes()), ("img/icon.svg", b"<svg/>")]);
+    let dark = ThemePackage::from_zip::<Ron>(archive, &limits).unwrap();
+
+    assert_eq!( dark.theme.name, "test" );
+
+    #[cfg(feature = "image")]
+    assert!( dark.images.contains_key("icon.svg") );
+
+    #[cfg(feature = "font")]
+    {
+        let archive = tar_gz(&[("theme.ron", tar::EntryType::Regular, THEME.as_bytes()), ("font/", tar::EntryType::Directory, b""), ("font/a.ttf", tar::EntryType::Regular, &font("A", 400))]);
+        let fonts = ThemePackage::from_tar_gz::<Ron>(archive, &limits).unwrap().fonts;
+
+        assert_eq!( fonts["a.ttf"].bytes, font("A", 400) );
+    }
+
+    // The theme must be at the root, even if the archive has a single folder.
+    let archive = zip(&[("Dark/theme.ron", THEME.as_bytes()), ("Dark/img/icon.svg", b"<svg/>")]);
+    assert_eq!( ThemePackage::from_zip::<Ron>(archive, &limits).unwrap_err().to_string(), "`theme.ron`: file not found" );
+
+    let archive = zip(&[("Dark/theme.ron", THEME.as_bytes()), ("Light/theme.ron", THEME.as_bytes())]);
+    assert_eq!( ThemePackage::from_zip::<Ron>(archive, &limits).unwrap_err().to_string(), "`theme.ron`: file not found" );
+
+    // Files are only read from supported archives.
+    let root = package("archive", &[("theme.rar", b"rar")]);
+    let error = ThemePackage::open_archive::<Ron>(root.join("theme.rar"), &limits).unwrap_err();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!( error.file, root.join("theme.rar") );
+}
+
+#[test]
+#[cfg(feature = "archive")]
+fn package_archive_safety() {
+    use crate::{ ThemePackage, package::{ archive::Limits, format::Ron } };
+
+    let limits = Limits::default();
+    let error = |e: crate::PackageError| e.to_string();
+
+    for path in ["../theme.ron", "/theme.ron", "img/../../theme.ron", "..\\theme.ron", "C:/theme.ron"] {
+        let archive = zip(&[(path, THEME.as_bytes())]);
+        assert_eq!( error( ThemePackage::from_zip::<Ron>(archive, &limits).unwrap_err() ), format!("`{}`: path is absolute or escapes the package", path) );
+    }
+
+    let archive = tar_gz(&[("../theme.ron", tar::EntryType::Regular, THEME.as_bytes())]);
+    assert_eq!( error( ThemePackage::from_tar_gz::<Ron>(archive, &limits).unwrap_err() ), "`../theme.ron`: path is absolute or escapes the package" );
+
+    // Links are rejected.
+    let mut writer = zip::ZipWriter::new( std::io::Cursor::new(Vec::new()) );
+    writer.add_symlink("theme.ron", "/etc/passwd", zip::write::FileOptions::default()).unwrap();
+    let archive = std::io::Cursor::new( writer.finish().unwrap().into_inner() );
+    assert_eq!( error( ThemePackage::from_zip::<Ron>(archive, &limits).unwrap_err() ), "`theme.ron`: links and special files are not allowed" );
+
+    for kind in [tar::EntryType::Symlink, tar::EntryType::Link] {
+        let archive = tar_gz(&[("theme.ron", kind, b"")]);
+        assert_eq!( error( ThemePackage::from_tar_gz::<Ron>(archive, &limits).unwrap_err() ), "`theme.ron`: links and special files are not allowed" );
+    }
+
+    // Limits are enforced.
+    let small = Limits { entries: 2, file: 16, total: 24 };
+
+    let archive = zip(&[("theme.ron", THEME.as_bytes())]);
+    assert_eq!( error( ThemePackage::from_zip::<Ron>(archive, &small).unwrap_err() ), "`theme.ron`: exceeds the limit of 16 bytes per file" );
+
+    let archive = zip(&[("a", &[0; 16]), ("b", &[0; 16])]);
+    assert_eq!( error( ThemePackage::from_zip::<Ron>(archive, &small).unwrap_err() ), "`b`: exceeds the limit of 24 bytes in total" );
+
+    let archive = tar_gz(&[("a", tar::EntryType::Regular, b""), ("b", tar::EntryType::Regular, b""), ("c", tar::EntryType::Regular, b"")]);
+    assert_eq!( error( ThemePackage::from_tar_gz::<Ron>(archive, &small).unwrap_err() ), "exceeds the limit of 2 entries" );
+}
+
+
+
+/// Metadata of the test theme.
+const META: &str = r##"(
+    authors: ["Jane Doe <jane@example.com>"],
+    license: Some("MIT OR Apache-2.0"),
+    version: "1.2.0",
+    homepage: Some("https://example.com/themes/test"),
+    tags: ["dark", "blue"],
+    variant: Dark,
+    preview: [Color(0, 0, 0, 1.0), "#2878dc"],
+    marcel: Some("0.1.0"),
+)"##;
+
+#[test]
+#[cfg(feature = "meta")]
+fn meta() {
+    use crate::{ ThemeMeta, meta::{ Variant, serial::ThemeMeta as SerialMeta } };
+
+    let meta = ThemeMeta::parse( &ron::from_str(META).unwrap() ).unwrap();
+
+    assert_eq!( meta.version, semver::Version::new(1, 2, 0) );
+    assert_eq!( meta.license.as_deref(), Some("MIT OR Apache-2.0") );
+    assert_eq!( meta.variant, Variant::Dark );
+    assert_eq!( iced::Color::from(meta.preview[1]), color("\"#2878dc\"") );
+    assert!( meta.is_supported() );
+
+    // Themes can require a newer version.
+    let mut serial: SerialMeta = ron::from_str(META).unwrap();
+    serial.marcel = Some("99.0.0".into());
+    assert!( !ThemeMeta::parse(&serial).unwrap().is_supported() );
+
+    // Versions and licenses are validated.
+    serial.version = "1.2".into();
+    serial.marcel = Some("latest".into());
+    serial.license = Some("MIT OR Proprietary-1.0".into());
+
+    let errors: Vec<String> = ThemeMeta::parse(&serial).unwrap_err().iter().map(|e| e.to_string()).collect();
+
+    assert_eq!( errors, [
+        "meta.version: invalid version `1.2`: unexpected end of input while parsing minor version number",
+        "meta.marcel: invalid version `latest`: unexpected character 'l' while parsing major version number",
+        "meta.license: invalid SPDX license `MIT OR Proprietary-1.0`: unknown term",
+    ] );
+}
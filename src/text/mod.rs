@@ -0,0 +1,67 @@
+//! Text theme.
+
+
+
+pub(crate) mod serial;
+
+
+
+use crate::{ Color, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
+
+use iced::widget::text::{ Appearance, StyleSheet, };
+
+use std::sync::Arc;
+
+
+
+#[derive(Clone, Debug)]
+pub struct Text {
+    /// Text color. Uses the default text color if not set.
+    pub color: Option<Arc<Color>>,
+}
+
+impl Text {
+    /// Attempts to create a theme from its serialized version.
+    pub(crate) fn create(serial: &serial::Text, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        match serial {
+            serial::Text::Color(key) => match theme.getcolor(key, "color", &mut errors) {
+                Some(color) => Ok( Text { color: Some(color) } ),
+                _ => Err(errors),
+            },
+
+            serial::Text::None => Ok( Text { color: None } ),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Text {
+        match &self.color {
+            Some(color) => serial::Text::Color( export.color(color) ),
+            _ => serial::Text::None,
+        }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Text { color: self.color.as_ref().map(|color| remap.color(color)) }
+    }
+}
+
+impl From<&Text> for iced::theme::Text {
+    fn from(text: &Text) -> Self {
+        match &text.color {
+            Some(color) => iced::theme::Text::Color( (**color).into() ),
+            _ => iced::theme::Text::Default,
+        }
+    }
+}
+
+impl StyleSheet for Text {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _: Self::Style) -> Appearance {
+        Appearance { color: self.color.as_ref().map(|c| (**c).into()) }
+    }
+}
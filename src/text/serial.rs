@@ -0,0 +1,18 @@
+//! `serde` compatible version of the text theme.
+
+
+
+use crate::serial::ColorRef;
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Text {
+    /// Reference to the text color.
+    Color(ColorRef),
+
+    /// The text uses the default color.
+    None,
+}
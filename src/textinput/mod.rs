@@ -6,7 +6,7 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Border, Color, Theme };
+use crate::{ Border, Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
 
 use iced::{
     widget::{
@@ -18,14 +18,17 @@ use iced::{
 
 use serial::Component;
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
 
 
 
 #[derive(Clone, Debug)]
 pub struct TextInput {
     /// State Themes of the text input.
-    /// In order: active, hovered, focused.
+    /// In order: active, hovered, focused, disabled.
     pub state: [Arc<State>; 4],
 
     /// Colors of the text input.
@@ -33,75 +36,68 @@ pub struct TextInput {
 }
 
 impl TextInput {
-    /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::TextInput, theme: &Theme) -> Result<Self, ()> {
-        // Get all the themes.
-        let active   = Self::state( &serial.active  , theme, 0 )?;
-        let hovered  = Self::state( &serial.hovered , theme, 1 )?;
-        let focused  = Self::state( &serial.focused , theme, 2 )?;
-        let disabled = Self::state( &serial.disabled, theme, 2 )?;
-        
-        // Find the first state theme that is not None.
-        let default = match (&active, &hovered, &focused, &disabled) {
-            (Some(d), _, _, _) => d.clone(),
-            (_, Some(d), _, _) => d.clone(),
-            (_, _, Some(d), _) => d.clone(),
-            (_, _, _, Some(d)) => d.clone(),
-
-            _ => return Err(()),
-        };
+    /// Names of the text input states, in order.
+    pub(crate) const STATES: [&'static str; 4] = [ "active", "hovered", "focused", "disabled", ];
+
+    /// Collects the states of the serialized text inputs for inheritance resolution.
+    pub(crate) fn group(serial: &HashMap<String, serial::TextInput>) -> Group<'_, Component, State> {
+        let mut group = Group::new( "textinput", &Self::STATES, State::from );
+
+        for (name, textinput) in serial {
+            group.insert( name, vec![ &textinput.active, &textinput.hovered, &textinput.focused, &textinput.disabled ] );
+        }
+
+        group
+    }
+
+    /// Creates a theme from its resolved states and its serialized colors.
+    pub(crate) fn create(name: &str, serial: &serial::TextInput, group: &Group<Component, State>, theme: &Theme) -> Result<Option<Self>, Vec<ParseError>> {
+        let mut errors = Vec::new();
 
         // Get the placeholder color.
-        let placeholder = match theme.color.get( &serial.placeholder ) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let placeholder = theme.getcolor( &serial.placeholder, "placeholder", &mut errors );
 
         // Get the value color.
-        let value = match theme.color.get( &serial.value ) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let value = theme.getcolor( &serial.value, "value", &mut errors );
 
         // Get the selection color.
-        let selection = match theme.color.get( &serial.selection ) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let selection = theme.getcolor( &serial.selection, "selection", &mut errors );
 
         // Get the disabled color.
-        let disabledc = match theme.color.get( &serial.disabledc ) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
+        let disabledc = theme.getcolor( &serial.disabledc, "disabledc", &mut errors );
+
+        let colors = match (placeholder, value, selection, disabledc) {
+            (Some(p), Some(v), Some(s), Some(d)) => [p, v, s, d],
+            _ => return Err(errors),
+        };
+
+        // The errors of the states are reported by the graph.
+        let state = match group.get(name) {
+            Some(state) => state,
+            _ => return Ok(None),
         };
 
-        Ok(TextInput {
-            state: [
-                active.unwrap_or(default.clone()),
-                hovered.unwrap_or(default.clone()),
-                focused.unwrap_or(default.clone()),
-                disabled.unwrap_or(default.clone()),
-            ],
-
-            colors: [
-                placeholder,
-                value,
-                selection,
-                disabledc,
-            ],
-        })
+        Ok( state.try_into().ok().map(|state| TextInput { state, colors }) )
     }
 
-    fn state(serial: &Component, theme: &Theme, index: usize) -> Result<Option<Arc<State>>, ()> {
-        match serial {
-            Component::Defined( state ) => Ok( Some( Arc::new( State::from(&state, &theme)? ) ) ),
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::TextInput {
+        let state = |i: usize| serial::Component::Defined( self.state[i].serial(export) );
 
-            Component::Inherited( name ) => match theme.textinput.get( name ) {
-                Some( textinput ) => Ok( Some( textinput.state[index].clone() ) ),
-                _ => Err(()),
-            },
+        serial::TextInput {
+            active: state(0), hovered: state(1), focused: state(2), disabled: state(3),
+            placeholder: export.color(&self.colors[0]),
+            value: export.color(&self.colors[1]),
+            selection: export.color(&self.colors[2]),
+            disabledc: export.color(&self.colors[3]),
+        }
+    }
 
-            Component::None => Ok( None ),
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        TextInput {
+            state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)),
+            colors: std::array::from_fn(|i| remap.color(&self.colors[i])),
         }
     }
 }
@@ -182,25 +178,39 @@ pub struct State {
 
 impl State {
     /// Attempts to create a theme from its serialized version.
-    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, ()> {
-        // Get the background color.
-        let background = match theme.color.get(&serial.background) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
 
         // Get the background color.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
+        let background = theme.getcolor(&serial.background, "background", &mut errors);
+
+        // Get the border.
+        let border = theme.getborder(&serial.border, "border", &mut errors);
 
         // Get the icon color.
-        let icon = match theme.color.get(&serial.icon) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+        let icon = theme.getcolor(&serial.icon, "icon", &mut errors);
 
-        Ok( State { background, border, icon } )
+        match (background, border, icon) {
+            (Some(background), Some(border), Some(icon)) => Ok( State { background, border, icon } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            background: export.color(&self.background),
+            border: export.border(&self.border),
+            icon: export.color(&self.icon),
+        }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            background: remap.color(&self.background),
+            border: remap.border(&self.border),
+            icon: remap.color(&self.icon),
+        }
     }
 }
@@ -2,6 +2,9 @@
 
 
 
+use crate::serial::{ BorderRef, ColorRef };
+use crate::theme::graph::Link;
+
 use serde_derive::{ Deserialize, Serialize };
 
 
@@ -21,28 +24,28 @@ pub struct TextInput {
     pub disabled: Component,
 
     /// Placeholder color.
-    pub placeholder: String,
+    pub placeholder: ColorRef,
 
     /// Value color.
-    pub value: String,
+    pub value: ColorRef,
 
     /// Selection color.
-    pub selection: String,
+    pub selection: ColorRef,
 
     /// Disabled color.
-    pub disabledc: String,
+    pub disabledc: ColorRef,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct State {
-    /// Key to the background color.
-    pub background: String,
+    /// Reference to the background color.
+    pub background: ColorRef,
 
-    /// Key to the border theme.
-    pub border: String,
+    /// Reference to the border theme.
+    pub border: BorderRef,
 
-    /// Key to the icon color.
-    pub icon: String,
+    /// Reference to the icon color.
+    pub icon: ColorRef,
 }
 
 
@@ -57,3 +60,15 @@ pub enum Component {
     /// The button state is not defined.
     None,
 }
+
+impl crate::theme::graph::Component for Component {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            Component::Defined( state ) => Link::Defined( state ),
+            Component::Inherited( name ) => Link::Inherited( name ),
+            Component::None => Link::None,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Export of the resolved styles of a theme to their serial versions.
+//! An entry that still resolves to what was parsed keeps its serial version,
+//! while an entry that was changed or added is rebuilt from its resolved value,
+//! referencing the colors, borders and gradients of the theme by key.
+
+
+
+use crate::{
+    Background, Border, Color, Gradient, Theme,
+    serial::{ self, BackgroundRef, BorderRef, ColorRef, Ref },
+};
+
+use std::{
+    cell::RefCell,
+    collections::{ HashMap, HashSet },
+    sync::Arc,
+};
+
+
+
+/// Context of the export of a theme.
+pub(crate) struct Export<'a> {
+    /// Theme being exported.
+    theme: &'a Theme,
+
+    /// Gradients that are not in the gradient table, with the keys they are exported with.
+    gradients: RefCell<Vec<(String, Arc<Gradient>)>>,
+}
+
+impl<'a> Export<'a> {
+    /// Creates the export context of a theme.
+    pub(crate) fn new(theme: &'a Theme) -> Self {
+        Export { theme, gradients: RefCell::new(Vec::new()) }
+    }
+
+    /// References a color by its key, or inline if it is not in the color table.
+    pub(crate) fn color(&self, color: &Arc<Color>) -> ColorRef {
+        match key(&self.theme.color, color) {
+            Some(key) => Ref::Key(key),
+            _ => Ref::Inline( Box::new( crate::color::serial::Color::Value(**color) ) ),
+        }
+    }
+
+    /// References a background color or gradient.
+    /// A gradient that is not in the gradient table is exported under a new key.
+    pub(crate) fn background(&self, background: &Background) -> BackgroundRef {
+        let gradient = match background {
+            Background::Color(color) => return self.color(color),
+            Background::Gradient(gradient) => gradient,
+        };
+
+        // Color keys take precedence, so a gradient shadowed by a color needs a new key.
+        if let Some(key) = key(&self.theme.gradient, gradient).filter(|k| !self.theme.color.contains_key(k)) {
+            return Ref::Key(key);
+        }
+
+        let mut gradients = self.gradients.borrow_mut();
+
+        if let Some((key, _)) = gradients.iter().find(|(_, g)| Arc::ptr_eq(g, gradient)) {
+            return Ref::Key(key.clone());
+        }
+
+        let key = (0..).map(|i| format!("gradient{}", i))
+            .find(|k| !self.theme.color.contains_key(k) && !self.theme.gradient.contains_key(k) && !gradients.iter().any(|(g, _)| g == k))
+            .expect("there are less gradients than integers");
+
+        gradients.push( (key.clone(), gradient.clone()) );
+
+        Ref::Key(key)
+    }
+
+    /// References a border by its key, or inline if it is not in the border table.
+    pub(crate) fn border(&self, border: &Arc<Border>) -> BorderRef {
+        match key(&self.theme.border, border) {
+            Some(key) => Ref::Key(key),
+            _ => Ref::Inline( Box::new( border.serial(self) ) ),
+        }
+    }
+
+    /// Exports a section of the theme.
+    /// An entry keeps its serial version if it was not replaced since it was parsed,
+    /// and neither were the entries it inherits from, given by `parents`.
+    /// Entries of the serial theme that failed to parse are kept as they are.
+    pub(crate) fn section<T, S: Clone>(
+        &self,
+        current: &HashMap<String, Arc<T>>,
+        parsed: Option<&HashMap<String, Arc<T>>>,
+        source: &HashMap<String, S>,
+        parents: impl Fn(&str) -> Vec<String>,
+        serial: impl Fn(&T) -> S,
+    ) -> HashMap<String, S> {
+        let empty = HashMap::new();
+        let parsed = parsed.unwrap_or(&empty);
+
+        let mut section: HashMap<String, S> = current.iter().map(|(name, value)| {
+            let entry = match kept(name, current, parsed, source, &parents, &mut HashSet::new()) {
+                true => source[name].clone(),
+                false => serial(value),
+            };
+
+            (name.clone(), entry)
+        }).collect();
+
+        for (name, entry) in source {
+            if !current.contains_key(name) && !parsed.contains_key(name) {
+                section.insert( name.clone(), entry.clone() );
+            }
+        }
+
+        section
+    }
+
+    /// Exports the gradients that are not in the gradient table.
+    pub(crate) fn gradients(&self) -> Vec<(String, serial::Gradient)> {
+        // Exporting a gradient does not register new gradients, as its stops are colors.
+        let gradients = self.gradients.borrow().clone();
+
+        gradients.into_iter().map(|(key, gradient)| (key, gradient.serial(self))).collect()
+    }
+}
+
+
+
+/// Finds the key of a value of a table by identity.
+/// The smallest key is used if the value is in the table more than once.
+fn key<T>(table: &HashMap<String, Arc<T>>, value: &Arc<T>) -> Option<String> {
+    table.iter()
+        .filter(|(_, v)| Arc::ptr_eq(v, value))
+        .map(|(k, _)| k)
+        .min()
+        .cloned()
+}
+
+/// Checks if an entry and the entries it inherits from were not replaced since they were parsed.
+/// Entries may inherit from each other through different states, so the entries
+/// already being checked are skipped, as their own check is still running.
+fn kept<T, S>(name: &str, current: &HashMap<String, Arc<T>>, parsed: &HashMap<String, Arc<T>>, source: &HashMap<String, S>, parents: &impl Fn(&str) -> Vec<String>, visited: &mut HashSet<String>) -> bool {
+    if !visited.insert( name.to_string() ) {
+        return true;
+    }
+
+    let unchanged = match (current.get(name), parsed.get(name)) {
+        (Some(current), Some(parsed)) => Arc::ptr_eq(current, parsed),
+        _ => false,
+    };
+
+    unchanged && source.contains_key(name) && parents(name).into_iter().all(|parent| kept(&parent, current, parsed, source, parents, visited))
+}
@@ -0,0 +1,410 @@
+//! Dependency graph of the inheritable styles of a theme.
+//! Every state of every composable style is a node of the graph, which
+//! depends on the state it inherits from or falls back on. The graph is
+//! resolved in topological order, so each state is created exactly once.
+
+
+
+use crate::{
+    Theme,
+    error::{ ErrorKind, ParseError },
+};
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+
+
+/// Serial component that can be defined, inherited or left undefined.
+pub(crate) trait Component {
+    /// Serial state of the component.
+    type Serial;
+
+    /// Returns the link of this component.
+    fn link(&self) -> Link<'_, Self::Serial>;
+}
+
+/// Link of a serial component to its value.
+pub(crate) enum Link<'a, S> {
+    /// The state is defined in place.
+    Defined( &'a S ),
+
+    /// The state is inherited from the same state of another entry.
+    Inherited( &'a str ),
+
+    /// The state falls back on another state of its entry.
+    None,
+}
+
+
+
+/// Dependency of a state of a style.
+pub(crate) enum Target<'a> {
+    /// The state is defined and has no dependency.
+    Defined,
+
+    /// The state depends on the same state of another entry.
+    Entry( &'a str ),
+
+    /// The state depends on another state of its own entry.
+    Slot( usize ),
+
+    /// The state is `None` and there is no state to fall back on.
+    Undefined,
+}
+
+/// Type erased access to a `Group`, used by the `Graph`.
+pub(crate) trait Resolve {
+    /// Section of the theme this group belongs to.
+    fn section(&self) -> &'static str;
+
+    /// Names of the states of the group.
+    fn fields(&self) -> &'static [&'static str];
+
+    /// Keys of all the entries of the group, in a stable order.
+    fn entries(&self) -> Vec<&str>;
+
+    /// Returns the dependency of the given state.
+    fn target(&self, entry: &str, slot: usize) -> Target<'_>;
+
+    /// Resolves the given state. All its dependencies must already be resolved.
+    fn resolve(&mut self, entry: &str, slot: usize, theme: &Theme) -> Result<(), Vec<ParseError>>;
+}
+
+
+
+/// Group of states of a style section that share the same type.
+/// A `None` state falls back on the state given by the fallbacks of the group,
+/// or on the first state of its entry that is not `None`.
+pub(crate) struct Group<'a, C: Component, T> {
+    /// Section of the theme.
+    section: &'static str,
+
+    /// Names of the states.
+    fields: &'static [&'static str],
+
+    /// State each `None` state falls back on, if it is not the first defined state.
+    fallbacks: &'static [Option<usize>],
+
+    /// Serial components of each entry.
+    entries: HashMap<&'a str, Vec<&'a C>>,
+
+    /// Creates a state from its serialized version.
+    build: fn(&C::Serial, &Theme) -> Result<T, Vec<ParseError>>,
+
+    /// Resolved states.
+    resolved: HashMap<(String, usize), Arc<T>>,
+}
+
+impl<'a, C: Component, T> Group<'a, C, T> {
+    /// Creates an empty group.
+    pub(crate) fn new(section: &'static str, fields: &'static [&'static str], build: fn(&C::Serial, &Theme) -> Result<T, Vec<ParseError>>) -> Self {
+        Group { section, fields, fallbacks: &[], entries: HashMap::new(), build, resolved: HashMap::new() }
+    }
+
+    /// Sets the state each `None` state falls back on. Must contain one slot per field.
+    /// The fallbacks must not form a loop, which is ensured if they only point to earlier states.
+    pub(crate) fn fallbacks(mut self, fallbacks: &'static [Option<usize>]) -> Self {
+        debug_assert_eq!(fallbacks.len(), self.fields.len());
+        self.fallbacks = fallbacks;
+        self
+    }
+
+    /// Adds the components of an entry. Must contain one component per field.
+    pub(crate) fn insert(&mut self, entry: &'a str, components: Vec<&'a C>) {
+        debug_assert_eq!(components.len(), self.fields.len());
+        self.entries.insert(entry, components);
+    }
+
+    /// Returns all the states of an entry, if they were all resolved.
+    pub(crate) fn get(&self, entry: &str) -> Option<Vec<Arc<T>>> {
+        (0..self.fields.len())
+            .map(|slot| self.resolved.get( &(entry.to_string(), slot) ).cloned())
+            .collect()
+    }
+
+    /// Returns the entries an entry inherits any of its states from.
+    pub(crate) fn parents(&self, entry: &str) -> Vec<String> {
+        let components = match self.entries.get(entry) {
+            Some(components) => components,
+            _ => return Vec::new(),
+        };
+
+        components.iter().filter_map(|c| match c.link() {
+            Link::Inherited(name) => Some( name.to_string() ),
+            _ => None,
+        }).collect()
+    }
+
+    /// Returns the state a `None` state falls back on.
+    fn fallback(&self, components: &[&C], slot: usize) -> Option<usize> {
+        match self.fallbacks.get(slot) {
+            Some(Some(fallback)) => Some(*fallback),
+            _ => components.iter().position(|c| !matches!(c.link(), Link::None)),
+        }
+    }
+}
+
+impl<'a, C: Component, T> Resolve for Group<'a, C, T> {
+    fn section(&self) -> &'static str {
+        self.section
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        self.fields
+    }
+
+    fn entries(&self) -> Vec<&str> {
+        let mut entries: Vec<&str> = self.entries.keys().copied().collect();
+        entries.sort_unstable();
+        entries
+    }
+
+    fn target(&self, entry: &str, slot: usize) -> Target<'_> {
+        let components = &self.entries[entry];
+
+        match components[slot].link() {
+            Link::Defined(_) => Target::Defined,
+
+            Link::Inherited(name) => Target::Entry(name),
+
+            Link::None => match self.fallback(components, slot) {
+                Some(fallback) => Target::Slot(fallback),
+                _ => Target::Undefined,
+            },
+        }
+    }
+
+    fn resolve(&mut self, entry: &str, slot: usize, theme: &Theme) -> Result<(), Vec<ParseError>> {
+        let components = &self.entries[entry];
+
+        let state = match components[slot].link() {
+            Link::Defined(serial) => match (self.build)(serial, theme) {
+                Ok(state) => Arc::new(state),
+                Err(e) => return Err( e.into_iter().map(|e| e.prefix(self.fields[slot]).locate(self.section, entry)).collect() ),
+            },
+
+            Link::Inherited(name) => self.resolved[ &(name.to_string(), slot) ].clone(),
+
+            Link::None => match self.fallback(components, slot) {
+                Some(fallback) => self.resolved[ &(entry.to_string(), fallback) ].clone(),
+                _ => unreachable!("undefined states are rejected by the graph"),
+            },
+        };
+
+        self.resolved.insert( (entry.to_string(), slot), state );
+
+        Ok(())
+    }
+}
+
+
+
+/// Node of the dependency graph.
+struct Node {
+    /// Index of the group of the node.
+    group: usize,
+
+    /// Key of the entry.
+    entry: String,
+
+    /// Index of the state in the entry.
+    slot: usize,
+
+    /// Nodes this node depends on.
+    deps: Vec<usize>,
+}
+
+/// Visit state of a node during the topological sort.
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Dependency graph of the states of a set of groups.
+pub(crate) struct Graph<'g> {
+    /// Groups of the graph.
+    groups: Vec<&'g mut dyn Resolve>,
+
+    /// All the states of all the groups.
+    nodes: Vec<Node>,
+
+    /// Nodes that can not be resolved.
+    failed: Vec<bool>,
+
+    /// Errors found while building and resolving the graph.
+    errors: Vec<ParseError>,
+}
+
+impl<'g> Graph<'g> {
+    /// Builds the dependency graph of the given groups.
+    pub(crate) fn new(groups: Vec<&'g mut dyn Resolve>) -> Self {
+        // Index all the nodes.
+        let mut nodes = Vec::new();
+        let mut index = HashMap::new();
+
+        for (g, group) in groups.iter().enumerate() {
+            for entry in group.entries() {
+                for slot in 0..group.fields().len() {
+                    index.insert( (g, entry.to_string(), slot), nodes.len() );
+                    nodes.push( Node { group: g, entry: entry.to_string(), slot, deps: Vec::new() } );
+                }
+            }
+        }
+
+        // Link the nodes to their dependencies.
+        let mut failed = vec![false; nodes.len()];
+        let mut errors = Vec::new();
+
+        for (n, node) in nodes.iter_mut().enumerate() {
+            let group = &groups[node.group];
+
+            let key = match group.target(&node.entry, node.slot) {
+                Target::Defined => continue,
+
+                Target::Entry(name) => (node.group, name.to_string(), node.slot),
+
+                Target::Slot(slot) => (node.group, node.entry.clone(), slot),
+
+                Target::Undefined => {
+                    // Report the entry once, on its first state.
+                    if node.slot == 0 {
+                        let field = match group.fields().len() {
+                            1 => group.fields()[0],
+                            _ => "",
+                        };
+
+                        errors.push( ParseError::new(field, ErrorKind::NoDefinedState).locate(group.section(), &node.entry) );
+                    }
+
+                    failed[n] = true;
+                    continue;
+                },
+            };
+
+            match index.get(&key) {
+                Some(dep) => node.deps.push(*dep),
+
+                _ => {
+                    errors.push( ParseError::new(group.fields()[node.slot], ErrorKind::UnresolvedInheritance(key.1)).locate(group.section(), &node.entry) );
+                    failed[n] = true;
+                },
+            }
+        }
+
+        Graph { groups, nodes, failed, errors }
+    }
+
+    /// Resolves all the nodes of the graph in topological order.
+    /// Returns the errors found. A node that depends on a failed node also fails.
+    /// It reports the failed entry it inherits from, while a state that falls
+    /// back on a failed state of its own entry is already covered by that error.
+    pub(crate) fn resolve(mut self, theme: &Theme) -> Vec<ParseError> {
+        for n in self.order() {
+            if self.failed[n] {
+                continue;
+            }
+
+            let failed: Vec<usize> = self.nodes[n].deps.iter().copied().filter(|d| self.failed[*d]).collect();
+
+            if !failed.is_empty() {
+                let node = &self.nodes[n];
+                let group = &self.groups[node.group];
+
+                for d in failed {
+                    if self.nodes[d].entry != node.entry {
+                        let kind = ErrorKind::FailedInheritance( self.nodes[d].entry.clone() );
+                        self.errors.push( ParseError::new(group.fields()[node.slot], kind).locate(group.section(), &node.entry) );
+                    }
+                }
+
+                self.failed[n] = true;
+                continue;
+            }
+
+            let Node { group, ref entry, slot, .. } = self.nodes[n];
+
+            if let Err(e) = self.groups[group].resolve(entry, slot, theme) {
+                self.errors.extend(e);
+                self.failed[n] = true;
+            }
+        }
+
+        self.errors
+    }
+
+    /// Sorts the nodes so that every node comes after its dependencies.
+    /// Nodes in a cycle are marked as failed and the cycle is reported.
+    fn order(&mut self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut visit = vec![Visit::Unvisited; self.nodes.len()];
+
+        for root in 0..self.nodes.len() {
+            if visit[root] != Visit::Unvisited {
+                continue;
+            }
+
+            // Depth first search with an explicit stack, so chains have no depth limit.
+            let mut stack = vec![(root, 0)];
+            visit[root] = Visit::InProgress;
+
+            while let Some((n, next)) = stack.last_mut() {
+                let n = *n;
+
+                match self.nodes[n].deps.get(*next) {
+                    Some(&dep) => {
+                        *next += 1;
+
+                        match visit[dep] {
+                            Visit::Unvisited => {
+                                visit[dep] = Visit::InProgress;
+                                stack.push((dep, 0));
+                            },
+
+                            Visit::InProgress => {
+                                let start = stack.iter().position(|(s, _)| *s == dep).expect("in progress nodes are in the stack");
+                                let cycle: Vec<usize> = stack[start..].iter().map(|(s, _)| *s).collect();
+                                self.cycle(&cycle);
+                            },
+
+                            Visit::Done => (),
+                        }
+                    },
+
+                    _ => {
+                        visit[n] = Visit::Done;
+                        order.push(n);
+                        stack.pop();
+                    },
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Marks all the nodes of a cycle as failed and reports the cycle.
+    fn cycle(&mut self, cycle: &[usize]) {
+        let mut path: Vec<String> = cycle.iter().map(|n| self.name(*n)).collect();
+        path.push( self.name(cycle[0]) );
+
+        for n in cycle {
+            self.failed[*n] = true;
+        }
+
+        let node = &self.nodes[cycle[0]];
+        let group = &self.groups[node.group];
+
+        self.errors.push( ParseError::new(group.fields()[node.slot], ErrorKind::CyclicInheritance(path)).locate(group.section(), &node.entry) );
+    }
+
+    /// Name of a node, as `entry.field`.
+    fn name(&self, n: usize) -> String {
+        let node = &self.nodes[n];
+        format!("{}.{}", node.entry, self.groups[node.group].fields()[node.slot])
+    }
+}
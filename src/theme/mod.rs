@@ -4,10 +4,26 @@
 
 pub mod serial;
 
+pub(crate) mod export;
+
+pub(crate) mod graph;
+
+pub(crate) mod remap;
+
 
 
 use crate::*;
 
+use crate::{
+    color::serial::Color as SerialColor,
+    error::{ ErrorKind, ParseError },
+    serial::{ BackgroundRef, BorderRef, ColorRef, Ref },
+};
+
+use export::Export;
+
+use graph::Graph;
+
 use std::{
     collections::HashMap,
     sync::Arc,
@@ -25,18 +41,37 @@ pub struct Theme {
     /// Used mainly as a helper in the serialized files.
     pub description: String,
 
+    /// Maps name keys to application themes.
+    pub application: HashMap<String, Arc<Application>>,
+
     /// Maps name keys to border themes.
     pub border: HashMap<String, Arc<Border>>,
 
     // Maps name keys to button themes.
     pub button: HashMap<String, Arc<Button>>,
 
+    /// Maps name keys to checkbox themes.
+    pub checkbox: HashMap<String, Arc<Checkbox>>,
+
     /// Maps name keys to colors.
     pub color: HashMap<String, Arc<Color>>,
 
     /// Maps name keys to container themes.
     pub container: HashMap<String, Arc<Container>>,
 
+    /// Maps name keys to gradients.
+    pub gradient: HashMap<String, Arc<Gradient>>,
+
+    #[cfg(feature = "image")]
+    /// Maps name keys to images.
+    /// Only filled when the theme is loaded from a package.
+    pub image: HashMap<String, Arc<Image>>,
+
+    #[cfg(feature = "font")]
+    /// Maps name keys to fonts.
+    /// Only filled when the theme is loaded from a package.
+    pub font: HashMap<String, Arc<Font>>,
+
     /// Maps name keys to panegrid themes.
     pub panegrid: HashMap<String, Arc<PaneGrid>>,
 
@@ -46,14 +81,41 @@ pub struct Theme {
     /// Maps name keys to progress bar themes.
     pub progressbar: HashMap<String, Arc<ProgressBar>>,
 
+    /// Maps name keys to radio themes.
+    pub radio: HashMap<String, Arc<Radio>>,
+
+    /// Maps name keys to rule themes.
+    pub rule: HashMap<String, Arc<Rule>>,
+
     /// Maps name keys to scrollable themes.
     pub scrollable: HashMap<String, Arc<Scrollable>>,
 
+    /// Maps name keys to slider themes.
+    /// Also used for vertical sliders.
+    pub slider: HashMap<String, Arc<Slider>>,
+
+    /// Maps name keys to svg themes.
+    pub svg: HashMap<String, Arc<Svg>>,
+
+    /// Maps name keys to text themes.
+    pub text: HashMap<String, Arc<Text>>,
+
     /// Maps name keys to text input themes.
     pub textinput: HashMap<String, Arc<TextInput>>,
 
+    /// Maps name keys to toggler themes.
+    pub toggler: HashMap<String, Arc<Toggler>>,
+
     /// Maps name keys to tooltip themes.
     pub tooltip: HashMap<String, Arc<Tooltip>>,
+
+    /// Styles as they were resolved from the serial theme.
+    /// Used by `to_serial` to detect the entries that were replaced.
+    pub(crate) parsed: Option<Arc<Theme>>,
+
+    /// Serial theme this theme was parsed from.
+    /// Keeps the keys and structure of the styles for `to_serial`.
+    pub(crate) source: serial::Theme,
 }
 
 impl Theme {
@@ -63,120 +125,387 @@ impl Theme {
             name: String::new(),
             description: String::new(),
 
+            application: HashMap::new(),
             border: HashMap::new(),
             button: HashMap::new(),
 
+            checkbox: HashMap::new(),
             color: HashMap::new(),
             container: HashMap::new(),
+            gradient: HashMap::new(),
+            #[cfg(feature = "image")]
+            image: HashMap::new(),
+            #[cfg(feature = "font")]
+            font: HashMap::new(),
 
             panegrid: HashMap::new(),
             picklist: HashMap::new(),
             progressbar: HashMap::new(),
 
+            radio: HashMap::new(),
+            rule: HashMap::new(),
+
             scrollable: HashMap::new(),
+            slider: HashMap::new(),
+            svg: HashMap::new(),
+
+            text: HashMap::new(),
 
             textinput: HashMap::new(),
+            toggler: HashMap::new(),
             tooltip: HashMap::new(),
+
+            parsed: None,
+            source: serial::Theme::default(),
         }
     }
 
     /// Attempts to create a theme from its serialized version.
-    pub fn parse(&mut self, theme: &serial::Theme) -> Result<usize, ()> {
+    /// Every entry that can be resolved is added to the theme, while the
+    /// entries that fail are reported in the returned list of errors.
+    pub fn parse(&mut self, theme: &serial::Theme) -> Result<(), Vec<ParseError>> {
         // Get the name and description.
         self.name = theme.name.clone();
         self.description = theme.description.clone();
 
-        // Number of failed elements.
-        let mut failed = 0;
+        // Keep the serial theme for exporting.
+        self.source = theme.clone();
 
-        // Deserialize all the colors.
-        let mut color = HashMap::new();
+        // Errors of the failed elements.
+        let mut errors = Vec::new();
 
-        for (k, v) in theme.color.iter() {
-            color.insert(k.clone(), Arc::new(*v));
-        }
+        // Resolve all the colors, computing the derived colors in dependency order.
+        let (color, e) = color::resolve( &theme.color );
+        errors.extend(e);
 
         // Set the color.
         self.color = color;
 
+        // Deserialize the gradients, as they only depend on colors.
+        for (name, serial) in &theme.gradient {
+            match Gradient::create( serial, self ) {
+                Ok(g) => { self.gradient.insert( name.clone(), Arc::new(g) ); },
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("gradient", name)) ),
+            }
+        }
+
+        // Deserialize the applications, as they only depend on colors.
+        for (name, serial) in &theme.application {
+            match Application::create( serial, self ) {
+                Ok(a) => { self.application.insert( name.clone(), Arc::new(a) ); },
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("application", name)) ),
+            }
+        }
+
         // Deserialize the borders, as they only depend on colors.
         for (name, serial) in &theme.border {
-            match Border::create( serial, &self ) {
+            match Border::create( serial, self ) {
                 Ok(b) => { self.border.insert( name.clone(), Arc::new(b) ); },
-                Err(_) => failed += 1,
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("border", name)) ),
             }
         }
 
         // Deserialize the progress bars, as they only depend on colors.
         for (name, serial) in &theme.progressbar {
-            match ProgressBar::create( serial, &self ) {
+            match ProgressBar::create( serial, self ) {
                 Ok(p) => { self.progressbar.insert( name.clone(), Arc::new(p) ); },
-                Err(_) => failed += 1,
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("progressbar", name)) ),
+            }
+        }
+
+        // Deserialize the rules, as they only depend on colors.
+        for (name, serial) in &theme.rule {
+            match Rule::create( serial, self ) {
+                Ok(r) => { self.rule.insert( name.clone(), Arc::new(r) ); },
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("rule", name)) ),
+            }
+        }
+
+        // Deserialize the svgs, as they only depend on colors.
+        for (name, serial) in &theme.svg {
+            match Svg::create( serial, self ) {
+                Ok(s) => { self.svg.insert( name.clone(), Arc::new(s) ); },
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("svg", name)) ),
+            }
+        }
+
+        // Deserialize the texts, as they only depend on colors.
+        for (name, serial) in &theme.text {
+            match Text::create( serial, self ) {
+                Ok(t) => { self.text.insert( name.clone(), Arc::new(t) ); },
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("text", name)) ),
             }
         }
 
         // Deserialize the containers, as they only depend on colors and borders.
         for (name, serial) in &theme.container {
-            match Container::create( serial, &self ) {
+            match Container::create( serial, self ) {
                 Ok(c) => { self.container.insert( name.clone(), Arc::new(c) ); },
-                Err(_) => failed += 1,
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("container", name)) ),
             }
         }
 
         // Deserialize the tooltips, as they only depend on colors and borders.
         for (name, serial) in &theme.tooltip {
-            match Tooltip::create( serial, &self ) {
+            match Tooltip::create( serial, self ) {
                 Ok(c) => { self.tooltip.insert( name.clone(), Arc::new(c) ); },
-                Err(_) => failed += 1,
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("tooltip", name)) ),
             }
         }
 
-        // Deserialize the composable.
-        // Allow a maximum depth of 10.
-        // TODO : Instead of maximum depth check for no changes in the set of buttons for a lock.
-        for _ in 0..10 {
-            // Deserialize the buttons.
-            for (name, serial) in &theme.button {
-                match Button::create( serial, &self ) {
-                    Ok(b) => { self.button.insert( name.clone(), Arc::new(b) ); },
-                    Err(_) => failed += 1,
-                }
+        // Collect the inheritable states of the composable styles.
+        let mut button = Button::group( &theme.button );
+        let mut checkbox = Checkbox::group( &theme.checkbox );
+        let (mut pgregion, mut pgstate) = PaneGrid::groups( &theme.panegrid );
+        let (mut plstate, mut plmenu) = Picklist::groups( &theme.picklist );
+        let mut radio = Radio::group( &theme.radio );
+        let mut scrollable = Scrollable::group( &theme.scrollable );
+        let mut slider = Slider::group( &theme.slider );
+        let mut textinput = TextInput::group( &theme.textinput );
+        let mut toggler = Toggler::group( &theme.toggler );
+
+        // Resolve all the states in dependency order.
+        let graph = Graph::new( vec![
+            &mut button,
+            &mut checkbox,
+            &mut pgregion, &mut pgstate,
+            &mut plstate, &mut plmenu,
+            &mut radio,
+            &mut scrollable,
+            &mut slider,
+            &mut textinput,
+            &mut toggler,
+        ] );
+
+        errors.extend( graph.resolve( self ) );
+
+        // Create the buttons.
+        for name in theme.button.keys() {
+            if let Some(b) = Button::create( name, &button ) {
+                self.button.insert( name.clone(), Arc::new(b) );
             }
+        }
 
-            // Deserialize the picklists.
-            for (name, serial) in &theme.panegrid {
-                match PaneGrid::create( serial, &self ) {
-                    Ok(p) => { self.panegrid.insert( name.clone(), Arc::new(p) ); },
-                    Err(_) => failed += 1,
-                }
+        // Create the checkboxes.
+        for name in theme.checkbox.keys() {
+            if let Some(c) = Checkbox::create( name, &checkbox ) {
+                self.checkbox.insert( name.clone(), Arc::new(c) );
             }
+        }
 
-            // Deserialize the picklists.
-            for (name, serial) in &theme.picklist {
-                match Picklist::create( serial, &self ) {
-                    Ok(p) => { self.picklist.insert( name.clone(), Arc::new(p) ); },
-                    Err(_) => failed += 1,
-                }
+        // Create the pane grids.
+        for name in theme.panegrid.keys() {
+            if let Some(p) = PaneGrid::create( name, &pgregion, &pgstate ) {
+                self.panegrid.insert( name.clone(), Arc::new(p) );
             }
+        }
 
-            // Deserialize the scrollables.
-            for (name, serial) in &theme.scrollable {
-                match Scrollable::create( serial, &self ) {
-                    Ok(s) => { self.scrollable.insert( name.clone(), Arc::new(s) ); },
-                    Err(_) => failed += 1,
-                }
+        // Create the picklists.
+        for name in theme.picklist.keys() {
+            if let Some(p) = Picklist::create( name, &plstate, &plmenu ) {
+                self.picklist.insert( name.clone(), Arc::new(p) );
             }
+        }
 
-            // Deserialize the text inputs.
-            for (name, serial) in &theme.textinput {
-                match TextInput::create( serial, &self ) {
-                    Ok(t) => { self.textinput.insert( name.clone(), Arc::new(t) ); },
-                    Err(_) => failed += 1,
-                }
+        // Create the radios.
+        for name in theme.radio.keys() {
+            if let Some(r) = Radio::create( name, &radio ) {
+                self.radio.insert( name.clone(), Arc::new(r) );
+            }
+        }
+
+        // Create the scrollables.
+        for name in theme.scrollable.keys() {
+            if let Some(s) = Scrollable::create( name, &scrollable ) {
+                self.scrollable.insert( name.clone(), Arc::new(s) );
+            }
+        }
+
+        // Create the sliders.
+        for name in theme.slider.keys() {
+            if let Some(s) = Slider::create( name, &slider ) {
+                self.slider.insert( name.clone(), Arc::new(s) );
+            }
+        }
+
+        // Create the text inputs.
+        for (name, serial) in &theme.textinput {
+            match TextInput::create( name, serial, &textinput, self ) {
+                Ok(Some(t)) => { self.textinput.insert( name.clone(), Arc::new(t) ); },
+                Ok(None) => (),
+                Err(e) => errors.extend( e.into_iter().map(|e| e.locate("textinput", name)) ),
+            }
+        }
+
+        // Create the togglers.
+        for name in theme.toggler.keys() {
+            if let Some(t) = Toggler::create( name, &toggler ) {
+                self.toggler.insert( name.clone(), Arc::new(t) );
+            }
+        }
+
+        // Keep the resolved styles for exporting.
+        self.parsed = Some( Arc::new( Theme { parsed: None, source: serial::Theme::default(), ..self.clone() } ) );
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Exports this theme to its serial version.
+    /// The entries that were not replaced since the theme was parsed keep their
+    /// serial version, with its keys and `Inherited`/`None` structure, while the
+    /// entries that were changed or added are written with their current values,
+    /// referencing the colors, borders and gradients of the theme by key.
+    /// Derived colors keep their expression unless they were replaced.
+    pub fn to_serial(&self) -> serial::Theme {
+        let export = Export::new(self);
+        let parsed = self.parsed.as_deref();
+        let source = &self.source;
+
+        // Groups of the composable styles, which give the entries each entry inherits from.
+        let button = Button::group( &source.button );
+        let checkbox = Checkbox::group( &source.checkbox );
+        let (pgregion, pgstate) = PaneGrid::groups( &source.panegrid );
+        let (plstate, plmenu) = Picklist::groups( &source.picklist );
+        let radio = Radio::group( &source.radio );
+        let scrollable = Scrollable::group( &source.scrollable );
+        let slider = Slider::group( &source.slider );
+        let textinput = TextInput::group( &source.textinput );
+        let toggler = Toggler::group( &source.toggler );
+
+        let none = |_: &str| Vec::new();
+
+        let mut serial = serial::Theme {
+            name: self.name.clone(),
+            description: self.description.clone(),
+
+            application: export.section( &self.application, parsed.map(|p| &p.application), &source.application, none, |a| a.serial(&export) ),
+            border: export.section( &self.border, parsed.map(|p| &p.border), &source.border, none, |b| b.serial(&export) ),
+            button: export.section( &self.button, parsed.map(|p| &p.button), &source.button, |n| button.parents(n), |b| b.serial(&export) ),
+            checkbox: export.section( &self.checkbox, parsed.map(|p| &p.checkbox), &source.checkbox, |n| checkbox.parents(n), |c| c.serial(&export) ),
+            color: export.section( &self.color, parsed.map(|p| &p.color), &source.color, none, |c| crate::color::serial::Color::Value(*c) ),
+            container: export.section( &self.container, parsed.map(|p| &p.container), &source.container, none, |c| c.serial(&export) ),
+            gradient: export.section( &self.gradient, parsed.map(|p| &p.gradient), &source.gradient, none, |g| g.serial(&export) ),
+
+            // Images and fonts are exported by path, which only the serial theme knows.
+            image: source.image.clone(),
+            font: source.font.clone(),
+
+            panegrid: export.section( &self.panegrid, parsed.map(|p| &p.panegrid), &source.panegrid, |n| [pgregion.parents(n), pgstate.parents(n)].concat(), |p| p.serial(&export) ),
+            picklist: export.section( &self.picklist, parsed.map(|p| &p.picklist), &source.picklist, |n| [plstate.parents(n), plmenu.parents(n)].concat(), |p| p.serial(&export) ),
+            progressbar: export.section( &self.progressbar, parsed.map(|p| &p.progressbar), &source.progressbar, none, |p| p.serial(&export) ),
+            radio: export.section( &self.radio, parsed.map(|p| &p.radio), &source.radio, |n| radio.parents(n), |r| r.serial(&export) ),
+            rule: export.section( &self.rule, parsed.map(|p| &p.rule), &source.rule, none, |r| r.serial(&export) ),
+            scrollable: export.section( &self.scrollable, parsed.map(|p| &p.scrollable), &source.scrollable, |n| scrollable.parents(n), |s| s.serial(&export) ),
+            slider: export.section( &self.slider, parsed.map(|p| &p.slider), &source.slider, |n| slider.parents(n), |s| s.serial(&export) ),
+            svg: export.section( &self.svg, parsed.map(|p| &p.svg), &source.svg, none, |s| s.serial(&export) ),
+            text: export.section( &self.text, parsed.map(|p| &p.text), &source.text, none, |t| t.serial(&export) ),
+            textinput: export.section( &self.textinput, parsed.map(|p| &p.textinput), &source.textinput, |n| textinput.parents(n), |t| t.serial(&export) ),
+            toggler: export.section( &self.toggler, parsed.map(|p| &p.toggler), &source.toggler, |n| toggler.parents(n), |t| t.serial(&export) ),
+            tooltip: export.section( &self.tooltip, parsed.map(|p| &p.tooltip), &source.tooltip, none, |t| t.serial(&export) ),
+        };
+
+        // Add the gradients of the styles that are not in the gradient table.
+        serial.gradient.extend( export.gradients() );
+
+        serial
+    }
+
+    /// Gets the referenced color, or creates an anonymous one if it is inline.
+    /// Inline derived colors are computed from the colors of the theme.
+    /// Records an error for `field` if the color can not be resolved.
+    pub(crate) fn getcolor(&self, color: &ColorRef, field: &str, errors: &mut Vec<ParseError>) -> Option<Arc<Color>> {
+        match color {
+            Ref::Key(key) => match self.color.get(key) {
+                Some(color) => Some( color.clone() ),
+                _ => { errors.push( ParseError::new(field, ErrorKind::MissingColor(key.clone())) ); None },
+            },
+
+            Ref::Inline(color) => match &**color {
+                SerialColor::Value(color) => Some( Arc::new(*color) ),
+
+                SerialColor::Derived(derived) => {
+                    let missing: Vec<&str> = derived.keys().into_iter().filter(|k| !self.color.contains_key(*k)).collect();
+
+                    for k in &missing {
+                        errors.push( ParseError::new(field, ErrorKind::MissingColor(k.to_string())) );
+                    }
+
+                    match missing.is_empty() {
+                        true => Some( Arc::new( derived.apply(|k| &self.color[k]) ) ),
+                        false => None,
+                    }
+                },
+            },
+        }
+    }
+
+    /// Gets the referenced background color or gradient.
+    /// Records an error for `field` if the background can not be resolved.
+    pub(crate) fn getbackground(&self, background: &BackgroundRef, field: &str, errors: &mut Vec<ParseError>) -> Option<Background> {
+        if let Ref::Key(key) = background {
+            if let (false, Some(gradient)) = (self.color.contains_key(key), self.gradient.get(key)) {
+                return Some( Background::Gradient( gradient.clone() ) );
+            }
+        }
+
+        self.getcolor(background, field, errors).map(Background::Color)
+    }
+
+    /// Links the images of the theme to the images of its package,
+    /// by path relative to the `img/` folder. Returns the missing images.
+    #[cfg(feature = "image")]
+    pub(crate) fn link_images(&mut self, images: &HashMap<String, Arc<Image>>) -> Vec<ParseError> {
+        let mut errors = Vec::new();
+
+        for (name, path) in &self.source.image {
+            match path.strip_prefix("img/").and_then(|path| images.get(path)) {
+                Some(image) => { self.image.insert( name.clone(), image.clone() ); },
+                _ => errors.push( ParseError::new("", ErrorKind::MissingImage(path.clone())).locate("image", name) ),
+            }
+        }
+
+        errors
+    }
+
+    /// Links the fonts of the theme to the fonts of its package,
+    /// by path relative to the `font/` folder. Returns the missing fonts.
+    #[cfg(feature = "font")]
+    pub(crate) fn link_fonts(&mut self, fonts: &HashMap<String, Arc<Font>>) -> Vec<ParseError> {
+        let mut errors = Vec::new();
+
+        for (name, path) in &self.source.font {
+            match path.strip_prefix("font/").and_then(|path| fonts.get(path)) {
+                Some(font) => { self.font.insert( name.clone(), font.clone() ); },
+                _ => errors.push( ParseError::new("", ErrorKind::MissingFont(path.clone())).locate("font", name) ),
             }
         }
 
-        Ok(failed)
+        errors
+    }
+
+    /// Gets the referenced border, or creates an anonymous one if it is inline.
+    /// Records an error for `field` if the border can not be resolved.
+    pub(crate) fn getborder(&self, border: &BorderRef, field: &str, errors: &mut Vec<ParseError>) -> Option<Arc<Border>> {
+        match border {
+            Ref::Key(key) => match self.border.get(key) {
+                Some(border) => Some( border.clone() ),
+                _ => { errors.push( ParseError::new(field, ErrorKind::MissingBorder(key.clone())) ); None },
+            },
+
+            Ref::Inline(serial) => match Border::create(serial, self) {
+                Ok(border) => Some( Arc::new(border) ),
+                Err(e) => { errors.extend( e.into_iter().map(|e| e.prefix(field)) ); None },
+            },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -196,6 +525,42 @@ impl core::fmt::Display for Theme {
             string += &format!("| |- \"{}\": {}\n", name, color);
         }
 
+        // Display the gradients.
+        string += "|- Gradients\n";
+
+        for (name, gradient) in &self.gradient {
+            string += &format!("| |- \"{}\": {}\n", name, gradient);
+        }
+
+        // Display the images.
+        #[cfg(feature = "image")]
+        {
+            string += "|- Images\n";
+
+            for (name, image) in &self.image {
+                string += &format!("| |- \"{}\": {}\n", name, image.alt.as_deref().unwrap_or(""));
+            }
+        }
+
+        // Display the fonts.
+        #[cfg(feature = "font")]
+        {
+            string += "|- Fonts\n";
+
+            for (name, font) in &self.font {
+                string += &format!("| |- \"{}\": {:?}\n", name, font.descriptor.family);
+            }
+        }
+
+        // Display the applications.
+        string += "|- Applications\n";
+
+        for (name, application) in &self.application {
+            string += &format!("| |- \"{}\"\n", name);
+            string += &format!("| | |- Background: {}\n", application.background);
+            string += &format!("| | |- Text color: {}\n", application.text);
+        }
+
         // Display the borders.
         string += "|- Borders\n";
 
@@ -210,14 +575,15 @@ impl core::fmt::Display for Theme {
         string += "|- Buttons\n";
 
         for (name, button) in &self.button {
-            const STATE: [&'static str; 4] = [ "Active  ", "Hovered ", "Pressed ", "Disabled", ];
+            const STATE: [&str; 4] = [ "Active  ", "Hovered ", "Pressed ", "Disabled", ];
 
             string += &format!("| |- \"{}\"\n", name);
 
-            for state in 0..4 {
-                string += &format!("| | |- {}\n", STATE[state]);
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
                 string += &format!("| | | |- Background: {}\n", button.state[state].background);
                 string += &format!("| | | |- Text color: {}\n", button.state[state].text);
+                string += &format!("| | | |- Shadow: ({:.1}, {:.1})\n", button.state[state].shadow.x, button.state[state].shadow.y);
                 string +=          "| | | |- Border:\n";
                 string += &format!("| | |   |- Color: {}\n", button.state[state].border.color);
                 string += &format!("| | |   |- Radius: {:?}\n", button.state[state].border.radius);
@@ -225,7 +591,31 @@ impl core::fmt::Display for Theme {
             }
         }
 
-        // Display the borders.
+        // Display the checkboxes.
+        string += "|- Checkboxes\n";
+
+        for (name, checkbox) in &self.checkbox {
+            const STATE: [&str; 4] = [ "Active  ", "Hovered ", "Active (unchecked) ", "Hovered (unchecked)", ];
+
+            string += &format!("| |- \"{}\"\n", name);
+
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
+                string += &format!("| | | |- Background: {}\n", checkbox.state[state].background);
+                string += &format!("| | | |- Checkmark:  {}\n", checkbox.state[state].checkmark);
+
+                if let Some(text) = &checkbox.state[state].text {
+                    string += &format!("| | | |- Text color: {}\n", text);
+                }
+
+                string +=          "| | | |- Border:\n";
+                string += &format!("| | |   |- Color: {}\n", checkbox.state[state].border.color);
+                string += &format!("| | |   |- Radius: {:?}\n", checkbox.state[state].border.radius);
+                string += &format!("| | |   |- Width:  {:.3}\n", checkbox.state[state].border.width);
+            }
+        }
+
+        // Display the containers.
         string += "|- Containers\n";
 
         for (name, container) in &self.container {
@@ -241,12 +631,12 @@ impl core::fmt::Display for Theme {
         string += "|- Pane Grids\n";
 
         for (name, panegrid) in &self.panegrid {
-            const STATE: [&'static str; 2] = [ "Picked  ", "Hovered ", ];
+            const STATE: [&str; 2] = [ "Picked  ", "Hovered ", ];
 
             string += &format!("| |- \"{}\"\n", name);
 
-            for state in 0..2 {
-                string += &format!("| | |- {}\n", STATE[state]);
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
                 string += &format!("| | | |- Line color: {}\n", panegrid.state[state].color);
                 string += &format!("| | | |- Line width: {}\n", panegrid.state[state].width);
             }
@@ -256,12 +646,12 @@ impl core::fmt::Display for Theme {
         string += "|- Picklists (Dropdowns)\n";
 
         for (name, picklist) in &self.picklist {
-            const STATE: [&'static str; 2] = [ "Active  ", "Hovered ", ];
+            const STATE: [&str; 2] = [ "Active  ", "Hovered ", ];
 
             string += &format!("| |- \"{}\"\n", name);
 
-            for state in 0..2 {
-                string += &format!("| | |- {}\n", STATE[state]);
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
                 string += &format!("| | | |- Background:        {}\n", picklist.state[state].background);
                 string += &format!("| | | |- Text color:        {}\n", picklist.state[state].text);
                 string += &format!("| | | |- Placeholder color: {}\n", picklist.state[state].placeholder);
@@ -295,16 +685,50 @@ impl core::fmt::Display for Theme {
             string += &format!("| | |- Radius: {:?}\n", progressbar.radius);
         }
 
+        // Display the radios.
+        string += "|- Radios\n";
+
+        for (name, radio) in &self.radio {
+            const STATE: [&str; 4] = [ "Active  ", "Hovered ", "Active (unselected) ", "Hovered (unselected)", ];
+
+            string += &format!("| |- \"{}\"\n", name);
+
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
+                string += &format!("| | | |- Background: {}\n", radio.state[state].background);
+                string += &format!("| | | |- Dot color:  {}\n", radio.state[state].dot);
+
+                if let Some(text) = &radio.state[state].text {
+                    string += &format!("| | | |- Text color: {}\n", text);
+                }
+
+                string +=          "| | | |- Border:\n";
+                string += &format!("| | |   |- Color: {}\n", radio.state[state].border.color);
+                string += &format!("| | |   |- Width:  {:.3}\n", radio.state[state].border.width);
+            }
+        }
+
+        // Display the rules.
+        string += "|- Rules\n";
+
+        for (name, rule) in &self.rule {
+            string += &format!("| |- \"{}\"\n", name);
+            string += &format!("| | |- Color: {}\n", rule.color);
+            string += &format!("| | |- Fill mode: {:?}\n", rule.fillmode);
+            string += &format!("| | |- Radius: {:?}\n", rule.radius);
+            string += &format!("| | |- Width:  {}\n", rule.width);
+        }
+
         // Display the scrollbars.
         string += "|- Scrollbars\n";
 
         for (name, scrollable) in &self.scrollable {
-            const STATE: [&'static str; 3] = [ "Active  ", "Hovered ", "Dragging", ];
+            const STATE: [&str; 3] = [ "Active  ", "Hovered ", "Dragging", ];
 
             string += &format!("| |- \"{}\"\n", name);
 
-            for state in 0..3 {
-                string += &format!("| | |- {}\n", STATE[state]);
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
                 string += &format!("| | | |- Scrollbar color: {}\n", scrollable.state[state].color);
                 string +=          "| | | |- Scrollbar border:\n";
                 string += &format!("| | |   |- Color: {}\n", scrollable.state[state].border.color);
@@ -319,16 +743,57 @@ impl core::fmt::Display for Theme {
             }
         }
 
+        // Display the sliders.
+        string += "|- Sliders\n";
+
+        for (name, slider) in &self.slider {
+            const STATE: [&str; 3] = [ "Active  ", "Hovered ", "Dragging", ];
+
+            string += &format!("| |- \"{}\"\n", name);
+
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
+                string += &format!("| | | |- Rail colors: {} / {}\n", slider.state[state].colors.0, slider.state[state].colors.1);
+                string += &format!("| | | |- Rail width:  {:.3}\n", slider.state[state].width);
+                string += &format!("| | | |- Rail radius: {:?}\n", slider.state[state].radius);
+                string += &format!("| | | |- Handle color: {}\n", slider.state[state].hcolor);
+                string += &format!("| | | |- Handle shape: {:?}\n", slider.state[state].shape);
+                string +=          "| | | |- Handle border:\n";
+                string += &format!("| | |   |- Color: {}\n", slider.state[state].hborder.color);
+                string += &format!("| | |   |- Width:  {:.3}\n", slider.state[state].hborder.width);
+            }
+        }
+
+        // Display the svgs.
+        string += "|- Svgs\n";
+
+        for (name, svg) in &self.svg {
+            match &svg.color {
+                Some(color) => string += &format!("| |- \"{}\": {}\n", name, color),
+                _ => string += &format!("| |- \"{}\": Default\n", name),
+            }
+        }
+
+        // Display the texts.
+        string += "|- Texts\n";
+
+        for (name, text) in &self.text {
+            match &text.color {
+                Some(color) => string += &format!("| |- \"{}\": {}\n", name, color),
+                _ => string += &format!("| |- \"{}\": Default\n", name),
+            }
+        }
+
         // Display the text input.
-        string += "|- Scrollbars\n";
+        string += "|- Text inputs\n";
 
         for (name, textinput) in &self.textinput {
-            const STATE: [&'static str; 3] = [ "Active  ", "Hovered ", "Focused", ];
+            const STATE: [&str; 4] = [ "Active  ", "Hovered ", "Focused ", "Disabled", ];
 
             string += &format!("| |- \"{}\"\n", name);
 
-            for state in 0..3 {
-                string += &format!("| | |- {}\n", STATE[state]);
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
                 string += &format!("| | | |- Background: {}\n", textinput.state[state].background);
                 string +=          "| | | |- Border:\n";
                 string += &format!("| | |   |- Color: {}\n", textinput.state[state].border.color);
@@ -339,6 +804,31 @@ impl core::fmt::Display for Theme {
             string += &format!("| | |- Placeholder color: {}\n", textinput.colors[0]);
             string += &format!("| | |- Value color:       {}\n", textinput.colors[1]);
             string += &format!("| | |- Selection color:   {}\n", textinput.colors[2]);
+            string += &format!("| | |- Disabled color:    {}\n", textinput.colors[3]);
+        }
+
+        // Display the togglers.
+        string += "|- Togglers\n";
+
+        for (name, toggler) in &self.toggler {
+            const STATE: [&str; 4] = [ "Active  ", "Hovered ", "Active (untoggled) ", "Hovered (untoggled)", ];
+
+            string += &format!("| |- \"{}\"\n", name);
+
+            for (state, label) in STATE.iter().enumerate() {
+                string += &format!("| | |- {}\n", label);
+                string += &format!("| | | |- Background: {}\n", toggler.state[state].background);
+
+                if let Some(border) = &toggler.state[state].bborder {
+                    string += &format!("| | | |- Background border: {}\n", border);
+                }
+
+                string += &format!("| | | |- Foreground: {}\n", toggler.state[state].foreground);
+
+                if let Some(border) = &toggler.state[state].fborder {
+                    string += &format!("| | | |- Foreground border: {}\n", border);
+                }
+            }
         }
 
         // Display the tooltip.
@@ -0,0 +1,68 @@
+//! Remapping of the resolved colors of a theme.
+//! Every value is remapped once, so the colors, borders, gradients and states
+//! shared by the styles of a theme are still shared by the remapped styles.
+
+
+
+use crate::{ Background, Border, Color, Gradient };
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::Arc,
+};
+
+
+
+/// Context of the remapping of the colors of a theme.
+pub(crate) struct Remap<'a> {
+    /// Function applied to every color.
+    function: &'a dyn Fn(&Color) -> Color,
+
+    /// Remapped values, by the address of the original value.
+    remapped: HashMap<*const (), Box<dyn Any>>,
+}
+
+impl<'a> Remap<'a> {
+    /// Creates the remapping context of a color function.
+    pub(crate) fn new(function: &'a dyn Fn(&Color) -> Color) -> Self {
+        Remap { function, remapped: HashMap::new() }
+    }
+
+    /// Remaps a shared value, reusing its remapped version if it was already remapped.
+    pub(crate) fn shared<T: 'static>(&mut self, value: &Arc<T>, remap: fn(&T, &mut Self) -> T) -> Arc<T> {
+        let address = Arc::as_ptr(value) as *const ();
+
+        if let Some(remapped) = self.remapped.get(&address).and_then(|r| r.downcast_ref::<Arc<T>>()) {
+            return remapped.clone();
+        }
+
+        let remapped = Arc::new( remap(value, self) );
+        self.remapped.insert( address, Box::new( remapped.clone() ) );
+
+        remapped
+    }
+
+    /// Remaps all the entries of a section of the theme.
+    pub(crate) fn section<T: 'static>(&mut self, section: &HashMap<String, Arc<T>>, remap: fn(&T, &mut Self) -> T) -> HashMap<String, Arc<T>> {
+        section.iter().map(|(name, value)| (name.clone(), self.shared(value, remap))).collect()
+    }
+
+    /// Remaps a color.
+    pub(crate) fn color(&mut self, color: &Arc<Color>) -> Arc<Color> {
+        self.shared(color, |color, remap| (remap.function)(color))
+    }
+
+    /// Remaps the colors of a background color or gradient.
+    pub(crate) fn background(&mut self, background: &Background) -> Background {
+        match background {
+            Background::Color(color) => Background::Color( self.color(color) ),
+            Background::Gradient(gradient) => Background::Gradient( self.shared(gradient, Gradient::remap) ),
+        }
+    }
+
+    /// Remaps the color of a border.
+    pub(crate) fn border(&mut self, border: &Arc<Border>) -> Arc<Border> {
+        self.shared(border, Border::remap)
+    }
+}
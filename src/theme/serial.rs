@@ -2,7 +2,7 @@
 
 
 
-use crate::serial::*;
+use crate::{ color::Notation, serial::* };
 
 use serde_derive::{ Deserialize, Serialize };
 
@@ -10,7 +10,7 @@ use std::collections::HashMap;
 
 
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Theme {
     /// Name of this theme.
     /// This can be used to index a set of themes inside a `Hashmap`.
@@ -20,18 +20,38 @@ pub struct Theme {
     /// Used mainly as a helper in the serialized files.
     pub description: String,
 
+    /// Maps name keys to application themes.
+    #[serde(default)]
+    pub application: HashMap<String, Application>,
+
     /// Maps name keys to border themes.
     pub border: HashMap<String, Border>,
 
     // Maps name keys to button themes.
     pub button: HashMap<String, Button>,
 
+    /// Maps name keys to checkbox themes.
+    #[serde(default)]
+    pub checkbox: HashMap<String, Checkbox>,
+
     /// Maps name keys to colors.
     pub color: HashMap<String, Color>,
 
     /// Maps name keys to containers.
     pub container: HashMap<String, Container>,
 
+    /// Maps name keys to gradients.
+    #[serde(default)]
+    pub gradient: HashMap<String, Gradient>,
+
+    /// Maps name keys to the paths of images in the package (e.g. `img/icons/save.svg`).
+    #[serde(default)]
+    pub image: HashMap<String, String>,
+
+    /// Maps name keys to the paths of fonts in the package (e.g. `font/Inter-Bold.ttf`).
+    #[serde(default)]
+    pub font: HashMap<String, String>,
+
     /// Maps name keys to pane grids.
     pub panegrid: HashMap<String, PaneGrid>,
 
@@ -41,12 +61,49 @@ pub struct Theme {
     /// Maps name keys to progress bar.
     pub progressbar: HashMap<String, ProgressBar>,
 
+    /// Maps name keys to radio.
+    #[serde(default)]
+    pub radio: HashMap<String, Radio>,
+
+    /// Maps name keys to rule.
+    #[serde(default)]
+    pub rule: HashMap<String, Rule>,
+
     /// Maps name keys to scrollable.
     pub scrollable: HashMap<String, Scrollable>,
 
+    /// Maps name keys to slider.
+    /// Also used for vertical sliders.
+    #[serde(default)]
+    pub slider: HashMap<String, Slider>,
+
+    /// Maps name keys to svg tints.
+    #[serde(default)]
+    pub svg: HashMap<String, Svg>,
+
+    /// Maps name keys to text styles.
+    #[serde(default)]
+    pub text: HashMap<String, Text>,
+
     /// Maps name keys to text input.
     pub textinput: HashMap<String, TextInput>,
 
+    /// Maps name keys to toggler.
+    #[serde(default)]
+    pub toggler: HashMap<String, Toggler>,
+
     /// Maps name keys to tooltip.
     pub tooltip: HashMap<String, Tooltip>,
 }
+
+impl Theme {
+    /// Sets the notation all the colors of the theme are serialized with.
+    /// Derived colors keep their expression.
+    pub fn set_notation(&mut self, notation: Notation) {
+        for color in self.color.values_mut() {
+            if let Color::Value(value) = color {
+                *value = value.with_notation(notation);
+            }
+        }
+    }
+}
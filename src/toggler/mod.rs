@@ -0,0 +1,150 @@
+//! Toggler theme.
+
+
+
+pub(crate) mod serial;
+
+
+
+use crate::{ Color, Theme, error::ParseError, theme::{ export::Export, graph::Group, remap::Remap }, };
+
+use iced::widget::toggler::{ Appearance, StyleSheet, };
+
+use serial::Component;
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+
+
+#[derive(Clone, Debug)]
+pub struct Toggler {
+    /// State Themes of the toggler.
+    /// In order: active, hovered, active untoggled, hovered untoggled.
+    pub state: [Arc<State>; 4],
+}
+
+impl Toggler {
+    /// Names of the toggler states, in order.
+    pub(crate) const STATES: [&'static str; 4] = [ "active", "hovered", "uactive", "uhovered", ];
+
+    /// States the untoggled states fall back on: `uhovered` on `uactive` and `uactive` on `active`.
+    const FALLBACKS: [Option<usize>; 4] = [ None, None, Some(0), Some(2), ];
+
+    /// Collects the states of the serialized togglers for inheritance resolution.
+    pub(crate) fn group(serial: &HashMap<String, serial::Toggler>) -> Group<'_, Component, State> {
+        let mut group = Group::new( "toggler", &Self::STATES, State::from ).fallbacks( &Self::FALLBACKS );
+
+        for (name, toggler) in serial {
+            group.insert( name, vec![ &toggler.active, &toggler.hovered, &toggler.uactive, &toggler.uhovered ] );
+        }
+
+        group
+    }
+
+    /// Creates a theme from its resolved states.
+    pub(crate) fn create(name: &str, group: &Group<Component, State>) -> Option<Self> {
+        let state = group.get(name)?;
+
+        Some( Toggler { state: state.try_into().ok()? } )
+    }
+
+    /// Builds the appearance of the given state.
+    fn appearance(&self, index: usize) -> Appearance {
+        Appearance {
+            background: (*self.state[index].background).into(),
+            background_border: self.state[index].bborder.as_ref().map(|c| (**c).into()),
+            foreground: (*self.state[index].foreground).into(),
+            foreground_border: self.state[index].fborder.as_ref().map(|c| (**c).into()),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Toggler {
+        let state = |i: usize| serial::Component::Defined( self.state[i].serial(export) );
+
+        serial::Toggler { active: state(0), hovered: state(1), uactive: state(2), uhovered: state(3) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Toggler { state: std::array::from_fn(|i| remap.shared(&self.state[i], State::remap)) }
+    }
+}
+
+impl StyleSheet for Toggler {
+    type Style = iced::Theme;
+
+    fn active(&self, _: &Self::Style, toggled: bool) -> Appearance {
+        match toggled {
+            true => self.appearance(0),
+            false => self.appearance(2),
+        }
+    }
+
+    fn hovered(&self, _: &Self::Style, toggled: bool) -> Appearance {
+        match toggled {
+            true => self.appearance(1),
+            false => self.appearance(3),
+        }
+    }
+}
+
+
+
+#[derive(Clone, Debug)]
+pub struct State {
+    /// Background color.
+    pub(crate) background: Arc<Color>,
+
+    /// Background border color, if any.
+    pub(crate) bborder: Option<Arc<Color>>,
+
+    /// Foreground color.
+    pub(crate) foreground: Arc<Color>,
+
+    /// Foreground border color, if any.
+    pub(crate) fborder: Option<Arc<Color>>,
+}
+
+impl State {
+    /// Attempts to create a theme from its serialized version.
+    fn from(serial: &serial::State, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background colors.
+        let background = theme.getcolor(&serial.background, "background", &mut errors);
+        let bborder = serial.bborder.as_ref().map(|key| theme.getcolor(key, "bborder", &mut errors));
+
+        // Get the foreground colors.
+        let foreground = theme.getcolor(&serial.foreground, "foreground", &mut errors);
+        let fborder = serial.fborder.as_ref().map(|key| theme.getcolor(key, "fborder", &mut errors));
+
+        match (background, foreground) {
+            (Some(background), Some(foreground)) if errors.is_empty() => Ok( State { background, bborder: bborder.flatten(), foreground, fborder: fborder.flatten() } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the state to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::State {
+        serial::State {
+            background: export.color(&self.background),
+            bborder: self.bborder.as_ref().map(|border| export.color(border)),
+            foreground: export.color(&self.foreground),
+            fborder: self.fborder.as_ref().map(|border| export.color(border)),
+        }
+    }
+
+    /// Remaps the colors of the state.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        State {
+            background: remap.color(&self.background),
+            bborder: self.bborder.as_ref().map(|border| remap.color(border)),
+            foreground: remap.color(&self.foreground),
+            fborder: self.fborder.as_ref().map(|border| remap.color(border)),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! `serde` compatible version of the toggler theme.
+
+
+
+use crate::serial::ColorRef;
+use crate::theme::graph::Link;
+
+use serde_derive::{ Deserialize, Serialize };
+
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Toggler {
+    /// Active toggled state.
+    pub active: Component,
+
+    /// Hovered toggled state.
+    pub hovered: Component,
+
+    /// Active untoggled state.
+    pub uactive: Component,
+
+    /// Hovered untoggled state.
+    pub uhovered: Component,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct State {
+    /// Reference to the background color.
+    pub background: ColorRef,
+
+    /// Reference to the background border color.
+    pub bborder: Option<ColorRef>,
+
+    /// Reference to the foreground color.
+    pub foreground: ColorRef,
+
+    /// Reference to the foreground border color.
+    pub fborder: Option<ColorRef>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Component {
+    /// The toggler state is defined.
+    Defined(State),
+
+    /// The toggler state is inherited from another toggler theme.
+    Inherited(String),
+
+    /// The toggler state is not defined.
+    None,
+}
+
+impl crate::theme::graph::Component for Component {
+    type Serial = State;
+
+    fn link(&self) -> Link<'_, State> {
+        match self {
+            Component::Defined( state ) => Link::Defined( state ),
+            Component::Inherited( name ) => Link::Inherited( name ),
+            Component::None => Link::None,
+        }
+    }
+}
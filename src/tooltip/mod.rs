@@ -6,7 +6,7 @@ pub(crate) mod serial;
 
 
 
-use crate::{ Border, Color, Theme, };
+use crate::{ Background, Border, Color, Theme, error::ParseError, theme::{ export::Export, remap::Remap }, };
 
 use iced::widget::container::{ Appearance, StyleSheet, };
 
@@ -16,8 +16,8 @@ use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct Tooltip {
-    /// Background color.
-    pub background: Arc<Color>,
+    /// Background color or gradient.
+    pub background: Background,
 
     /// Text color.
     pub text: Arc<Color>,
@@ -28,26 +28,32 @@ pub struct Tooltip {
 
 impl Tooltip {
     /// Attempts to create a theme from its serialized version.
-    pub(crate) fn create(serial: &serial::Tooltip, theme: &Theme) -> Result<Self, ()> {
-        // Get the background color.
-        let background = match theme.color.get(&serial.background) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
+    pub(crate) fn create(serial: &serial::Tooltip, theme: &Theme) -> Result<Self, Vec<ParseError>> {
+        let mut errors = Vec::new();
+
+        // Get the background color or gradient.
+        let background = theme.getbackground(&serial.background, "background", &mut errors);
 
         // Get the text color.
-        let text = match theme.color.get(&serial.text) {
-            Some(color) => color.clone(),
-            _ => return Err(()),
-        };
-
-        // Get the background color.
-        let border = match theme.border.get(&serial.border) {
-            Some(border) => border.clone(),
-            _ => return Err(()),
-        };
-
-        Ok( Tooltip { background, text, border } )
+        let text = theme.getcolor(&serial.text, "text", &mut errors);
+
+        // Get the border.
+        let border = theme.getborder(&serial.border, "border", &mut errors);
+
+        match (background, text, border) {
+            (Some(background), Some(text), Some(border)) => Ok( Tooltip { background, text, border } ),
+            _ => Err(errors),
+        }
+    }
+
+    /// Exports the theme to its serialized version.
+    pub(crate) fn serial(&self, export: &Export) -> serial::Tooltip {
+        serial::Tooltip { background: export.background(&self.background), text: export.color(&self.text), border: export.border(&self.border) }
+    }
+
+    /// Remaps the colors of the theme.
+    pub(crate) fn remap(&self, remap: &mut Remap) -> Self {
+        Tooltip { background: remap.background(&self.background), text: remap.color(&self.text), border: remap.border(&self.border) }
     }
 }
 
@@ -57,7 +63,7 @@ impl StyleSheet for Tooltip {
     fn appearance(&self, _: &Self::Style) -> Appearance {
         Appearance {
             text_color: None,
-            background: Some( (*self.background).into() ),
+            background: Some( (&self.background).into() ),
             border_radius: self.border.radius,
             border_width: self.border.width,
             border_color: (*self.border.color).into(),
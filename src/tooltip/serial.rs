@@ -2,18 +2,20 @@
 
 
 
+use crate::serial::{ BackgroundRef, BorderRef, ColorRef };
+
 use serde_derive::{ Deserialize, Serialize };
 
 
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Tooltip {
-    /// Key to the background color.
-    pub background: String,
+    /// Reference to the background color or gradient.
+    pub background: BackgroundRef,
 
-    /// Key to the text color.
-    pub text: String,
+    /// Reference to the text color.
+    pub text: ColorRef,
 
-    /// Key to the border definition.
-    pub border: String,
+    /// Reference to the border definition.
+    pub border: BorderRef,
 }
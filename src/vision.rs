@@ -0,0 +1,168 @@
+//! Color vision deficiency filters of themes.
+//! A filter simulates how a theme is seen with a color vision deficiency, or
+//! corrects (daltonizes) its colors to make them easier to tell apart.
+
+
+
+use crate::{
+    Application, Border, Button, Checkbox, Color, Container, Gradient, PaneGrid, Picklist, ProgressBar,
+    Radio, Rule, Scrollable, Slider, Svg, Text, TextInput, Theme, Toggler, Tooltip,
+    theme::remap::Remap,
+};
+
+
+
+/// Color vision deficiency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Deficiency {
+    /// Missing long wavelength (red) cones.
+    Protanopia,
+
+    /// Missing medium wavelength (green) cones.
+    Deuteranopia,
+
+    /// Missing short wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// Simulation matrix of the deficiency in linear RGB (Machado et al. 2009, full severity).
+    const fn simulation(self) -> [[f32; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [ 0.152286,  1.052583, -0.204868],
+                [ 0.114503,  0.786281,  0.099216],
+                [-0.003882, -0.048116,  1.051998],
+            ],
+
+            Deficiency::Deuteranopia => [
+                [ 0.367322,  0.860646, -0.227968],
+                [ 0.280085,  0.672501,  0.047413],
+                [-0.011820,  0.042940,  0.968881],
+            ],
+
+            Deficiency::Tritanopia => [
+                [ 1.255528, -0.076749, -0.178779],
+                [-0.078411,  0.930809,  0.147602],
+                [ 0.004733,  0.691367,  0.303900],
+            ],
+        }
+    }
+
+    /// Matrix that moves the color information lost by the deficiency into the visible channels.
+    const fn correction(self) -> [[f32; 3]; 3] {
+        match self {
+            Deficiency::Protanopia | Deficiency::Deuteranopia => [
+                [0.0, 0.0, 0.0],
+                [0.7, 1.0, 0.0],
+                [0.7, 0.0, 1.0],
+            ],
+
+            Deficiency::Tritanopia => [
+                [1.0, 0.0, 0.7],
+                [0.0, 1.0, 0.7],
+                [0.0, 0.0, 0.0],
+            ],
+        }
+    }
+}
+
+
+
+/// Transform of the colors of a theme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Filter {
+    /// Shows the colors as seen with the deficiency.
+    Simulate( Deficiency ),
+
+    /// Shifts the colors to keep them distinguishable with the deficiency.
+    Daltonize( Deficiency ),
+}
+
+impl Filter {
+    /// Applies the filter to a color, keeping its alpha and notation.
+    pub fn apply(&self, color: &Color) -> Color {
+        let iced::Color { r, g, b, a } = iced::Color::from(*color);
+        let rgb = [linear(r), linear(g), linear(b)];
+
+        let rgb = match *self {
+            Filter::Simulate(deficiency) => multiply(deficiency.simulation(), rgb),
+
+            Filter::Daltonize(deficiency) => {
+                let simulated = multiply(deficiency.simulation(), rgb);
+                let error = [rgb[0] - simulated[0], rgb[1] - simulated[1], rgb[2] - simulated[2]];
+                let shift = multiply(deficiency.correction(), error);
+
+                [rgb[0] + shift[0], rgb[1] + shift[1], rgb[2] + shift[2]]
+            },
+        };
+
+        Color::new( gamma(rgb[0]), gamma(rgb[1]), gamma(rgb[2]), a ).with_notation( color.notation() )
+    }
+}
+
+
+
+impl Theme {
+    /// Returns a copy of the theme with every color remapped through the filter.
+    /// The filter is applied once to every resolved color, so the styles that
+    /// shared a color, border, gradient or state still share them once filtered.
+    pub fn filter(&self, filter: Filter) -> Theme {
+        let apply = |color: &Color| filter.apply(color);
+        let mut remap = Remap::new(&apply);
+
+        Theme {
+            application: remap.section( &self.application, Application::remap ),
+            border: remap.section( &self.border, Border::remap ),
+            button: remap.section( &self.button, Button::remap ),
+            checkbox: remap.section( &self.checkbox, Checkbox::remap ),
+            color: self.color.iter().map(|(name, color)| (name.clone(), remap.color(color))).collect(),
+            container: remap.section( &self.container, Container::remap ),
+            gradient: remap.section( &self.gradient, Gradient::remap ),
+            panegrid: remap.section( &self.panegrid, PaneGrid::remap ),
+            picklist: remap.section( &self.picklist, Picklist::remap ),
+            progressbar: remap.section( &self.progressbar, ProgressBar::remap ),
+            radio: remap.section( &self.radio, Radio::remap ),
+            rule: remap.section( &self.rule, Rule::remap ),
+            scrollable: remap.section( &self.scrollable, Scrollable::remap ),
+            slider: remap.section( &self.slider, Slider::remap ),
+            svg: remap.section( &self.svg, Svg::remap ),
+            text: remap.section( &self.text, Text::remap ),
+            textinput: remap.section( &self.textinput, TextInput::remap ),
+            toggler: remap.section( &self.toggler, Toggler::remap ),
+            tooltip: remap.section( &self.tooltip, Tooltip::remap ),
+
+            // Images and fonts are not colors and are kept as they are.
+            ..self.clone()
+        }
+    }
+}
+
+
+
+/// Multiplies a matrix and a vector.
+fn multiply(matrix: [[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    let row = |r: [f32; 3]| r[0] * v[0] + r[1] * v[1] + r[2] * v[2];
+
+    [row(matrix[0]), row(matrix[1]), row(matrix[2])]
+}
+
+/// Converts an sRGB channel in `[0, 1]` to linear RGB.
+fn linear(c: f32) -> f32 {
+    match c <= 0.04045 {
+        true => c / 12.92,
+        false => ((c + 0.055) / 1.055).powf(2.4),
+    }
+}
+
+/// Converts a linear RGB channel to an sRGB byte, clipping it to `[0, 1]`.
+fn gamma(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+
+    let c = match c <= 0.0031308 {
+        true => c * 12.92,
+        false => 1.055 * c.powf(1.0 / 2.4) - 0.055,
+    };
+
+    (c * 255.0).round() as u8
+}